@@ -1,14 +1,18 @@
 import os
+import signal
+import asyncio
 import logging
 from pathlib import Path
 from datetime import datetime
-from fastapi import FastAPI, HTTPException
+from fastapi import FastAPI, HTTPException, Request
 from fastapi.middleware.cors import CORSMiddleware
 from fastapi.exceptions import RequestValidationError
 from starlette.middleware.base import BaseHTTPMiddleware
 import json
+from urllib.parse import quote
 from dotenv import load_dotenv
 
+from .version import __version__
 from .util.errors import http_exception_handler, validation_exception_handler, general_exception_handler
 from .routers import sessions as sessions_router
 from .routers import images as images_router
@@ -253,6 +257,96 @@ app.add_exception_handler(Exception, general_exception_handler)
 def health():
     return {"ok": True}
 
+@app.get("/instance")
+def instance():
+    """Echo the launch token so the desktop app can tell its own backend from another server"""
+    return {"instance_id": os.environ.get("SWIFTGRADE_INSTANCE_ID")}
+
+@app.get("/version")
+def version():
+    """Version this backend was built as, so the desktop app can spot a stale sidecar"""
+    return {"version": __version__}
+
+@app.post("/shutdown")
+async def shutdown(request: Request):
+    """Exit cleanly when asked by the desktop app (BACKEND_GRACEFUL_SHUTDOWN)"""
+    if request.client is None or request.client.host not in ("127.0.0.1", "::1", "localhost"):
+        raise HTTPException(status_code=403, detail="Shutdown is only accepted from this machine")
+    # Signal after this response is sent; uvicorn then drains in-flight requests and exits
+    asyncio.get_running_loop().call_later(0.1, signal.raise_signal, signal.SIGINT)
+    return {"ok": True}
+
+@app.get("/config/runtime")
+def runtime_config():
+    """Settings as this process is actually using them (read once at startup)"""
+    return {"grading_max_concurrency": grade_router.MAX_CONCURRENCY}
+
+@app.get("/config/validate")
+async def validate_config():
+    """Per-setting verdicts that need the backend's view: model availability, bucket access"""
+    import httpx
+
+    settings = []
+
+    def verdict(key, status, message):
+        settings.append({"key": key, "status": status, "message": message})
+
+    for var in ['OPENROUTER_API_KEY', 'SUPABASE_URL', 'SUPABASE_SERVICE_ROLE_KEY']:
+        if not os.getenv(var):
+            verdict(var, "error", "Not set")
+
+    api_key = os.getenv("OPENROUTER_API_KEY")
+    model = os.getenv("OPENROUTER_MODEL")
+    if api_key:
+        try:
+            async with httpx.AsyncClient() as client:
+                response = await client.get(
+                    f"{grade_router.OPENROUTER_BASE_URL}/models",
+                    headers={"Authorization": f"Bearer {api_key}"},
+                    timeout=10.0,
+                )
+            if response.status_code == 200:
+                verdict("OPENROUTER_API_KEY", "ok", "OpenRouter accepted the key")
+                if model:
+                    ids = {m.get("id") for m in response.json().get("data", []) if isinstance(m, dict)}
+                    if model in ids:
+                        verdict("OPENROUTER_MODEL", "ok", f"{model} is available")
+                    else:
+                        verdict("OPENROUTER_MODEL", "error", f"{model} is not in OpenRouter's model list")
+            elif response.status_code in (401, 403):
+                verdict("OPENROUTER_API_KEY", "error", f"OpenRouter rejected the key (HTTP {response.status_code})")
+            else:
+                verdict("OPENROUTER_API_KEY", "warning", f"OpenRouter returned HTTP {response.status_code}")
+        except Exception as e:
+            verdict("OPENROUTER_API_KEY", "warning", f"Could not reach OpenRouter: {e}")
+
+    supabase_url = os.getenv("SUPABASE_URL")
+    service_key = os.getenv("SUPABASE_SERVICE_ROLE_KEY")
+    bucket = os.getenv("SUPABASE_STORAGE_BUCKET", "grading-images")
+    if supabase_url and service_key:
+        try:
+            async with httpx.AsyncClient() as client:
+                response = await client.get(
+                    f"{supabase_url.rstrip('/')}/storage/v1/bucket/{quote(bucket, safe='')}",
+                    headers={"apikey": service_key, "Authorization": f"Bearer {service_key}"},
+                    timeout=10.0,
+                )
+            if response.status_code == 200:
+                verdict("SUPABASE_STORAGE_BUCKET", "ok", f"Bucket {bucket} is accessible")
+            elif response.status_code in (401, 403):
+                verdict("SUPABASE_SERVICE_ROLE_KEY", "error", f"Supabase denied access to bucket {bucket}")
+            elif response.status_code in (400, 404):
+                verdict("SUPABASE_STORAGE_BUCKET", "error", f"Bucket {bucket} does not exist")
+            else:
+                verdict("SUPABASE_STORAGE_BUCKET", "warning", f"Supabase returned HTTP {response.status_code}")
+        except Exception as e:
+            verdict("SUPABASE_URL", "warning", f"Could not reach Supabase: {e}")
+
+    if grade_router.MAX_CONCURRENCY < 1:
+        verdict("GRADING_MAX_CONCURRENCY", "error", "Must be at least 1")
+
+    return {"settings": settings}
+
 @app.get("/health/detailed")
 async def detailed_health():
     """Comprehensive health check for all backend components"""
@@ -0,0 +1,3 @@
+# Version baked into the packaged backend. release.yml rewrites this from package.json
+# before PyInstaller runs; the desktop app compares it with EXPECTED_BACKEND_VERSION.
+__version__ = "1.0.0"
@@ -14,6 +14,8 @@ from pathlib import Path
 import threading
 import time
 
+from backend_selfcheck import run_self_check
+
 # Global shutdown flag
 shutdown_event = threading.Event()
 
@@ -228,7 +230,15 @@ def main():
     parser.add_argument('--port', type=int, default=8000, help='Port to run the server on')
     parser.add_argument('--host', type=str, default='127.0.0.1', help='Host to bind to')
     parser.add_argument('--log-level', type=str, default='info', help='Log level')
+    parser.add_argument('--instance-id', type=str, default=None,
+                        help='Token the desktop app uses to recognise this process (also read from SWIFTGRADE_INSTANCE_ID)')
+    parser.add_argument('--self-check', action='store_true',
+                        help='Check the environment and imports, print the results and exit')
     args = parser.parse_args()
+    if args.instance_id:
+        os.environ['SWIFTGRADE_INSTANCE_ID'] = args.instance_id
+    if args.self_check:
+        run_self_check(setup_environment)
     
     print(f"Mark Grading Assistant Backend Launcher", flush=True)
     print(f"=" * 40, flush=True)
@@ -10,6 +10,8 @@ import argparse
 from pathlib import Path
 import logging
 
+from backend_selfcheck import REQUIRED_ENV_KEYS, run_self_check
+
 # Ensure proper encoding for Windows console output
 if sys.platform == "win32":
     import codecs
@@ -88,8 +90,7 @@ def setup_environment():
     print(f"Searched locations: {[str(p) for p in env_locations if p]}")
     
     # Check if we have any required environment variables
-    required_vars = ['OPENROUTER_API_KEY', 'SUPABASE_URL', 'SUPABASE_SERVICE_ROLE_KEY']
-    missing_vars = [var for var in required_vars if not os.getenv(var)]
+    missing_vars = [var for var in REQUIRED_ENV_KEYS if not os.getenv(var)]
     
     if missing_vars:
         print(f"Warning: Missing required environment variables: {missing_vars}")
@@ -103,7 +104,15 @@ def main():
     parser.add_argument('--port', type=int, default=8000, help='Port to run the server on')
     parser.add_argument('--host', type=str, default='127.0.0.1', help='Host to bind to')
     parser.add_argument('--log-level', type=str, default='info', help='Log level')
+    parser.add_argument('--instance-id', type=str, default=None,
+                        help='Token the desktop app uses to recognise this process (also read from SWIFTGRADE_INSTANCE_ID)')
+    parser.add_argument('--self-check', action='store_true',
+                        help='Check the environment and imports, print the results and exit')
     args = parser.parse_args()
+    if args.instance_id:
+        os.environ['SWIFTGRADE_INSTANCE_ID'] = args.instance_id
+    if args.self_check:
+        run_self_check(setup_environment)
     
     print(f"Starting Mark Grading Assistant Backend on {args.host}:{args.port}...")
     
@@ -0,0 +1,31 @@
+"""
+--self-check support shared by backend_launcher.py and backend_runner.py.
+"""
+
+import os
+import sys
+import traceback
+
+REQUIRED_ENV_KEYS = ('OPENROUTER_API_KEY', 'SUPABASE_URL', 'SUPABASE_SERVICE_ROLE_KEY')
+
+def run_self_check(setup_environment):
+    """Load the environment and import everything the server needs, then exit without serving (--self-check)"""
+    print(f"Self-check: Python {sys.version.split()[0]} on {sys.platform}, frozen={getattr(sys, 'frozen', False)}", flush=True)
+    setup_environment()
+    ok = True
+    for key in REQUIRED_ENV_KEYS:
+        if os.environ.get(key):
+            print(f"[OK] {key} is set", flush=True)
+        else:
+            print(f"[ERROR] {key} is not set", flush=True)
+            ok = False
+    for module in ('uvicorn', 'fastapi', 'httpx', 'app.main', 'app.supabase_client'):
+        try:
+            __import__(module)
+            print(f"[OK] imported {module}", flush=True)
+        except Exception as e:
+            print(f"[ERROR] importing {module} failed: {e}", flush=True)
+            traceback.print_exc()
+            ok = False
+    print("Self-check passed" if ok else "Self-check failed", flush=True)
+    sys.exit(0 if ok else 1)
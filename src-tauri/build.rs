@@ -1,3 +1,87 @@
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+
 fn main() {
+    // The sidecar version this build expects; defaults to the app version in lib.rs
+    println!("cargo:rerun-if-env-changed=EXPECTED_BACKEND_VERSION");
+    
+    embed_sidecar_hash();
+    embed_build_info();
     tauri_build::build()
 }
+
+// Expose the bundled sidecar's SHA-256 as SIDECAR_SHA256 so the app can detect a
+// corrupted or replaced binary. Release CI pins the value from the signed binary instead,
+// since signing changes the file after this runs.
+fn embed_sidecar_hash() {
+    println!("cargo:rerun-if-env-changed=SIDECAR_SHA256");
+    if let Ok(hash) = std::env::var("SIDECAR_SHA256") {
+        println!("cargo:rustc-env=SIDECAR_SHA256={}", hash.to_ascii_lowercase());
+        return;
+    }
+    
+    let target = std::env::var("TARGET").unwrap_or_default();
+    let extension = if target.contains("windows") { ".exe" } else { "" };
+    let sidecar = PathBuf::from("binaries").join(format!("backend-{}{}", target, extension));
+    println!("cargo:rerun-if-changed={}", sidecar.display());
+    
+    let hash = File::open(&sidecar).and_then(|mut file| {
+        let mut hasher = Sha256::new();
+        io::copy(&mut file, &mut hasher)?;
+        Ok(hasher.finalize())
+    });
+    match hash {
+        Ok(digest) => {
+            let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+            println!("cargo:rustc-env=SIDECAR_SHA256={}", hex);
+        }
+        Err(e) => println!("cargo:warning=Not embedding sidecar hash ({:?}: {})", sidecar, e),
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+// BUILD_GIT_COMMIT, BUILD_TIMESTAMP and BUILD_TARGET for get_build_info. CI can set
+// BUILD_GIT_COMMIT and SOURCE_DATE_EPOCH when building from an exported tree.
+// The script only reruns when HEAD, the checked-out ref or those variables change, so
+// without SOURCE_DATE_EPOCH the timestamp is when it last ran, not when the binary was
+// linked. Uncommitted edits aren't tracked either, so there's no "-dirty" marker.
+fn embed_build_info() {
+    println!("cargo:rerun-if-env-changed=BUILD_GIT_COMMIT");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    
+    // Rebuild when HEAD moves, whether it's detached or on a branch
+    if let Some(git_dir) = git(&["rev-parse", "--git-dir"]) {
+        println!("cargo:rerun-if-changed={}/HEAD", git_dir);
+        if let Some(head_ref) = git(&["symbolic-ref", "-q", "HEAD"]) {
+            println!("cargo:rerun-if-changed={}/{}", git_dir, head_ref);
+        }
+    }
+    
+    let commit = std::env::var("BUILD_GIT_COMMIT")
+        .ok()
+        .or_else(|| git(&["rev-parse", "--short=12", "HEAD"]));
+    println!("cargo:rustc-env=BUILD_GIT_COMMIT={}", commit.unwrap_or_else(|| "unknown".to_string()));
+    
+    let timestamp = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|v| v.parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0)
+        });
+    println!("cargo:rustc-env=BUILD_TIMESTAMP={}", timestamp);
+    println!("cargo:rustc-env=BUILD_TARGET={}", std::env::var("TARGET").unwrap_or_default());
+}
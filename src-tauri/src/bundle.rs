@@ -0,0 +1,111 @@
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+
+// Streaming .zip writer: each entry is deflated straight to disk as it's written, so
+// nothing larger than one write is held in memory.
+pub struct ZipWriter {
+    zip: zip::ZipWriter<File>,
+    options: SimpleFileOptions,
+}
+
+// The entry currently being written
+pub struct EntryWriter<'a> {
+    zip: &'a mut zip::ZipWriter<File>,
+}
+
+impl Write for EntryWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.zip.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.zip.flush()
+    }
+}
+
+impl ZipWriter {
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let modified = zip::DateTime::try_from(chrono::Local::now().naive_local()).unwrap_or_default();
+        // Entries are streamed, so their size isn't known up front; zip64 headers keep
+        // anything past 4 GiB readable
+        let options = SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .large_file(true)
+            .last_modified_time(modified);
+        Ok(Self { zip: zip::ZipWriter::new(File::create(path)?), options })
+    }
+
+    // Add one entry whose contents are produced by `fill`
+    pub fn entry<F>(&mut self, name: &str, fill: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut EntryWriter<'_>) -> io::Result<()>,
+    {
+        self.zip.start_file(name, self.options)?;
+        fill(&mut EntryWriter { zip: &mut self.zip })
+    }
+
+    pub fn finish(self) -> io::Result<()> {
+        self.zip.finish()?.sync_all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("swiftgrade-bundle-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn entries_round_trip() {
+        let path = temp_path("round-trip.zip");
+        let mut zip = ZipWriter::create(&path).unwrap();
+        zip.entry("diagnostics.json", |w| w.write_all(b"{\"ok\":true}")).unwrap();
+        zip.entry("logs/backend.log", |w| {
+            for i in 0..1000 {
+                writeln!(w, "line {}", i)?;
+            }
+            Ok(())
+        })
+        .unwrap();
+        zip.finish().unwrap();
+
+        let mut archive = zip::ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(archive.len(), 2);
+        let mut json = String::new();
+        archive.by_name("diagnostics.json").unwrap().read_to_string(&mut json).unwrap();
+        assert_eq!(json, "{\"ok\":true}");
+        let mut log = String::new();
+        archive.by_name("logs/backend.log").unwrap().read_to_string(&mut log).unwrap();
+        assert_eq!(log.lines().count(), 1000);
+        assert_eq!(log.lines().last(), Some("line 999"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn entries_carry_the_export_time() {
+        let path = temp_path("timestamp.zip");
+        let mut zip = ZipWriter::create(&path).unwrap();
+        zip.entry("config.env", |w| w.write_all(b"KEY=value\n")).unwrap();
+        zip.finish().unwrap();
+
+        let mut archive = zip::ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        let modified = archive.by_index(0).unwrap().last_modified().unwrap();
+        assert!(modified.year() > 1980);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn failed_fill_surfaces_the_error() {
+        let path = temp_path("failed.zip");
+        let mut zip = ZipWriter::create(&path).unwrap();
+        let result = zip.entry("broken", |_| Err(io::Error::other("source went away")));
+        assert_eq!(result.unwrap_err().to_string(), "source went away");
+        drop(zip);
+        let _ = std::fs::remove_file(&path);
+    }
+}
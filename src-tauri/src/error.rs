@@ -0,0 +1,134 @@
+use serde::Serialize;
+use std::fmt;
+use std::io;
+use std::path::{Component, Path};
+
+// Coarse failure classes the frontend can branch on; serialized as snake_case
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorKind {
+    PermissionDenied,
+    FileLocked,
+    DiskFull,
+    Timeout,
+    Unauthorized,
+    Network,
+    Io,
+    Invalid,
+    // No .env and the required settings aren't in the environment either
+    EnvNotFound,
+    Other,
+}
+
+// Structured command error: `{ "kind": "...", "message": "..." }` on the JS side,
+// plus the `path` involved for file errors
+#[derive(Debug, Clone, Serialize)]
+pub struct AppError {
+    pub kind: ErrorKind,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+impl AppError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self { kind, message: message.into(), path: None }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::new(ErrorKind::Invalid, message)
+    }
+
+    // Connection failures: timeouts are reported separately so the UI can say so
+    // The URL is stripped since it can carry a project identifier
+    pub fn from_request(service: &str, error: reqwest::Error) -> Self {
+        let error = error.without_url();
+        if error.is_timeout() {
+            Self::new(ErrorKind::Timeout, format!("{} did not respond in time", service))
+        } else {
+            Self::new(ErrorKind::Network, format!("Failed to reach {}: {}", service, error))
+        }
+    }
+
+    // Classify an I/O failure, prefixing the message with what we were doing
+    pub fn from_io(context: &str, error: &io::Error) -> Self {
+        Self::new(classify_io(error), format!("{}: {}", context, error))
+    }
+
+    // Like `from_io`, but records the file and, when the disk is full, names the volume
+    // instead of passing on the bare OS message
+    pub fn from_io_at(context: &str, path: &Path, error: &io::Error) -> Self {
+        let kind = classify_io(error);
+        let message = if kind == ErrorKind::DiskFull {
+            format!(
+                "{}: there is no space left on {}. Free up some space and try again.",
+                context,
+                volume_of(path)
+            )
+        } else {
+            format!("{}: {}", context, error)
+        };
+        Self { kind, message, path: Some(path.to_string_lossy().to_string()) }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        Self::new(ErrorKind::Other, message)
+    }
+}
+
+// For the string-error commands that call into converted code
+impl From<AppError> for String {
+    fn from(error: AppError) -> Self {
+        error.message
+    }
+}
+
+// Windows sharing/lock violations and disk-full codes have no stable io::ErrorKind
+#[cfg(target_os = "windows")]
+const LOCKED_OS_ERRORS: &[i32] = &[32, 33];
+#[cfg(not(target_os = "windows"))]
+const LOCKED_OS_ERRORS: &[i32] = &[];
+
+#[cfg(target_os = "windows")]
+const DISK_FULL_OS_ERRORS: &[i32] = &[39, 112];
+#[cfg(not(target_os = "windows"))]
+const DISK_FULL_OS_ERRORS: &[i32] = &[28, 122]; // ENOSPC, EDQUOT
+
+// The drive (C:\) on Windows; elsewhere the nearest existing directory stands in for the mount
+fn volume_of(path: &Path) -> String {
+    if let Some(Component::Prefix(prefix)) = path.components().next() {
+        return format!("{}\\", prefix.as_os_str().to_string_lossy());
+    }
+    let dir = path.ancestors().skip(1).find(|p| p.is_dir()).unwrap_or(path);
+    format!("the drive holding {}", dir.display())
+}
+
+pub fn classify_io(error: &io::Error) -> ErrorKind {
+    if let Some(code) = error.raw_os_error() {
+        if LOCKED_OS_ERRORS.contains(&code) {
+            return ErrorKind::FileLocked;
+        }
+        if DISK_FULL_OS_ERRORS.contains(&code) {
+            return ErrorKind::DiskFull;
+        }
+    }
+    match error.kind() {
+        io::ErrorKind::PermissionDenied => ErrorKind::PermissionDenied,
+        io::ErrorKind::StorageFull | io::ErrorKind::QuotaExceeded => ErrorKind::DiskFull,
+        _ => ErrorKind::Io,
+    }
+}
+
+// Lock and (on Windows, where antivirus holds files briefly) permission errors usually clear up
+pub fn is_transient(error: &io::Error) -> bool {
+    let kind = classify_io(error);
+    kind == ErrorKind::FileLocked || (cfg!(target_os = "windows") && kind == ErrorKind::PermissionDenied)
+}
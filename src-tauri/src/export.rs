@@ -0,0 +1,446 @@
+use rust_xlsxwriter::{ColNum, Format, Workbook, Worksheet, XlsxError};
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+// Subset of the backend's GET /results/{session_id} response
+#[derive(Debug, Deserialize)]
+pub struct ResultsResponse {
+    pub results_by_question: HashMap<String, HashMap<String, Vec<ResultItem>>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResultItem {
+    pub try_index: u32,
+    pub marks_awarded: Option<f64>,
+    #[serde(default)]
+    pub token_usage: Option<TokenUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TokenUsage {
+    #[serde(default)]
+    pub total_tokens: Option<u64>,
+    #[serde(default)]
+    pub cost_estimate: Option<f64>,
+}
+
+// One row per model attempt, one column per question
+pub struct ResultsTable {
+    pub questions: Vec<String>,
+    pub rows: Vec<AttemptRow>,
+}
+
+pub struct AttemptRow {
+    pub model: String,
+    pub try_index: u32,
+    pub marks: Vec<Option<f64>>,
+    pub total_tokens: Option<u64>,
+    pub cost: Option<f64>,
+}
+
+// Per-model aggregates over all of its attempts
+pub struct ModelSummary<'a> {
+    pub model: &'a str,
+    pub tries: usize,
+    pub average: f64,
+    pub lowest: f64,
+    pub highest: f64,
+    pub total_tokens: Option<u64>,
+    pub cost: Option<f64>,
+}
+
+impl AttemptRow {
+    pub fn total(&self) -> f64 {
+        // Folded from 0.0 because an empty f64 sum is -0.0, which shows up as "-0"
+        self.marks.iter().flatten().fold(0.0, |acc, m| acc + m)
+    }
+}
+
+// Order "q2" before "q10" by comparing digit runs numerically
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        match (a.chars().next(), b.chars().next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let a_end = a.find(|c: char| !c.is_ascii_digit()).unwrap_or(a.len());
+                let b_end = b.find(|c: char| !c.is_ascii_digit()).unwrap_or(b.len());
+                let (a_num, b_num) = (a[..a_end].trim_start_matches('0'), b[..b_end].trim_start_matches('0'));
+                let ord = a_num.len().cmp(&b_num.len()).then_with(|| a_num.cmp(b_num));
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+                a = &a[a_end..];
+                b = &b[b_end..];
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a = &a[x.len_utf8()..];
+                b = &b[y.len_utf8()..];
+            }
+        }
+    }
+}
+
+impl ResultsTable {
+    pub fn from_response(response: &ResultsResponse) -> Self {
+        let mut questions: Vec<String> = response.results_by_question.keys().cloned().collect();
+        questions.sort_by(|a, b| natural_cmp(a, b));
+        let column: HashMap<&str, usize> = questions.iter().enumerate().map(|(i, q)| (q.as_str(), i)).collect();
+
+        let mut attempts: BTreeMap<(String, u32), AttemptRow> = BTreeMap::new();
+        for (question, by_model) in &response.results_by_question {
+            for (model, items) in by_model {
+                for item in items {
+                    let row = attempts
+                        .entry((model.clone(), item.try_index))
+                        .or_insert_with(|| AttemptRow {
+                            model: model.clone(),
+                            try_index: item.try_index,
+                            marks: vec![None; questions.len()],
+                            total_tokens: None,
+                            cost: None,
+                        });
+                    row.marks[column[question.as_str()]] = item.marks_awarded;
+                    // Token usage is recorded per attempt, so every question repeats it
+                    if let Some(usage) = &item.token_usage {
+                        row.total_tokens = row.total_tokens.or(usage.total_tokens);
+                        row.cost = row.cost.or(usage.cost_estimate);
+                    }
+                }
+            }
+        }
+
+        Self { questions, rows: attempts.into_values().collect() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn model_summaries(&self) -> Vec<ModelSummary<'_>> {
+        // Rows are sorted by model, so each model's attempts are contiguous
+        self.rows
+            .chunk_by(|a, b| a.model == b.model)
+            .map(|attempts| {
+                let totals: Vec<f64> = attempts.iter().map(AttemptRow::total).collect();
+                ModelSummary {
+                    model: &attempts[0].model,
+                    tries: attempts.len(),
+                    average: totals.iter().fold(0.0, |acc, t| acc + t) / totals.len() as f64,
+                    lowest: totals.iter().copied().fold(f64::INFINITY, f64::min),
+                    highest: totals.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                    total_tokens: attempts.iter().filter_map(|a| a.total_tokens).reduce(|a, b| a + b),
+                    cost: attempts.iter().filter_map(|a| a.cost).reduce(|a, b| a + b),
+                }
+            })
+            .collect()
+    }
+
+    fn header(&self) -> Vec<&str> {
+        let mut header = vec!["Model", "Try"];
+        header.extend(self.questions.iter().map(String::as_str));
+        header.extend(["Total", "Tokens", "Cost (USD)"]);
+        header
+    }
+}
+
+const SUMMARY_HEADER: [&str; 7] = ["Model", "Tries", "Average total", "Lowest total", "Highest total", "Tokens", "Cost (USD)"];
+
+// Bold, frozen header row, a wide first column for model names and narrower data columns
+fn start_sheet<'a>(workbook: &'a mut Workbook, name: &str, header: &[&str]) -> Result<&'a mut Worksheet, XlsxError> {
+    let bold = Format::new().set_bold();
+    let sheet = workbook.add_worksheet().set_name(name)?;
+    for (col, title) in header.iter().enumerate() {
+        sheet.write_string_with_format(0, col as ColNum, *title, &bold)?;
+    }
+    sheet.set_freeze_panes(1, 0)?;
+    sheet.set_column_width(0, 32)?;
+    if header.len() > 1 {
+        sheet.set_column_range_width(1, header.len() as ColNum - 1, 14)?;
+    }
+    Ok(sheet)
+}
+
+// Missing (and non-finite) values are left as empty cells
+fn write_optional(sheet: &mut Worksheet, row: u32, col: ColNum, value: Option<f64>) -> Result<(), XlsxError> {
+    if let Some(value) = value.filter(|v| v.is_finite()) {
+        sheet.write_number(row, col, value)?;
+    }
+    Ok(())
+}
+
+fn build_workbook(session_id: &str, table: &ResultsTable) -> Result<Workbook, XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let sheet = start_sheet(&mut workbook, "Results", &table.header())?;
+    for (i, row) in table.rows.iter().enumerate() {
+        let r = i as u32 + 1;
+        sheet.write_string(r, 0, &row.model)?;
+        sheet.write_number(r, 1, row.try_index)?;
+        let mut col: ColNum = 2;
+        for mark in &row.marks {
+            write_optional(sheet, r, col, *mark)?;
+            col += 1;
+        }
+        write_optional(sheet, r, col, Some(row.total()))?;
+        write_optional(sheet, r, col + 1, row.total_tokens.map(|t| t as f64))?;
+        write_optional(sheet, r, col + 2, row.cost)?;
+    }
+
+    let sheet = start_sheet(&mut workbook, "Summary", &SUMMARY_HEADER)?;
+    let summaries = table.model_summaries();
+    for (i, summary) in summaries.iter().enumerate() {
+        let r = i as u32 + 1;
+        sheet.write_string(r, 0, summary.model)?;
+        sheet.write_number(r, 1, summary.tries as f64)?;
+        write_optional(sheet, r, 2, Some(summary.average))?;
+        write_optional(sheet, r, 3, Some(summary.lowest))?;
+        write_optional(sheet, r, 4, Some(summary.highest))?;
+        write_optional(sheet, r, 5, summary.total_tokens.map(|t| t as f64))?;
+        write_optional(sheet, r, 6, summary.cost)?;
+    }
+    // One blank row, then the session for reference
+    let r = summaries.len() as u32 + 2;
+    sheet.write_string(r, 0, "Session")?;
+    sheet.write_string(r, 1, session_id)?;
+
+    Ok(workbook)
+}
+
+// Workbook with the per-attempt marks and a per-model summary sheet
+pub fn write_xlsx(path: &Path, session_id: &str, table: &ResultsTable) -> io::Result<()> {
+    build_workbook(session_id, table)
+        .and_then(|mut workbook| workbook.save(path))
+        .map_err(|e| match e {
+            XlsxError::IoError(e) => e,
+            other => io::Error::other(other),
+        })
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn csv_number(value: Option<f64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+// Same per-attempt layout as the workbook's Results sheet
+pub fn write_csv(path: &Path, table: &ResultsTable) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    let header: Vec<String> = table.header().into_iter().map(csv_field).collect();
+    writeln!(out, "{}", header.join(","))?;
+
+    for row in &table.rows {
+        let mut fields = vec![csv_field(&row.model), row.try_index.to_string()];
+        fields.extend(row.marks.iter().map(|m| csv_number(*m)));
+        fields.push(row.total().to_string());
+        fields.push(row.total_tokens.map(|t| t.to_string()).unwrap_or_default());
+        fields.push(csv_number(row.cost));
+        writeln!(out, "{}", fields.join(","))?;
+    }
+    out.flush()
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn html_number(value: Option<f64>) -> String {
+    value.map(|v| format!("{}", (v * 100.0).round() / 100.0)).unwrap_or_default()
+}
+
+// Table headers repeat on every printed page and rows never split across pages
+const PRINT_CSS: &str = "
+body { font-family: -apple-system, 'Segoe UI', Roboto, sans-serif; font-size: 11pt; color: #111; margin: 24px; }
+h1 { font-size: 18pt; margin: 0 0 4px; }
+h2 { font-size: 13pt; margin: 24px 0 8px; }
+.meta { color: #555; font-size: 9pt; margin-bottom: 16px; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #bbb; padding: 4px 6px; text-align: right; }
+th:first-child, td:first-child { text-align: left; }
+th { background: #eee; }
+thead { display: table-header-group; }
+tr { page-break-inside: avoid; }
+@page { margin: 15mm; }
+@media print { body { margin: 0; } }
+";
+
+// Print-friendly report: the per-model summary followed by every attempt's marks
+pub fn render_html(session_id: &str, table: &ResultsTable) -> String {
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>Grading report</title><style>");
+    html.push_str(PRINT_CSS);
+    html.push_str("</style></head><body><h1>Grading report</h1>");
+    html.push_str(&format!("<div class=\"meta\">Session {}</div>", html_escape(session_id)));
+
+    html.push_str("<h2>Summary</h2><table><thead><tr>");
+    for column in SUMMARY_HEADER {
+        html.push_str(&format!("<th>{}</th>", column));
+    }
+    html.push_str("</tr></thead><tbody>");
+    for summary in table.model_summaries() {
+        html.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            html_escape(summary.model),
+            summary.tries,
+            html_number(Some(summary.average)),
+            html_number(Some(summary.lowest)),
+            html_number(Some(summary.highest)),
+            summary.total_tokens.map(|t| t.to_string()).unwrap_or_default(),
+            summary.cost.map(|c| format!("{:.4}", c)).unwrap_or_default(),
+        ));
+    }
+    html.push_str("</tbody></table>");
+
+    html.push_str("<h2>Results</h2><table><thead><tr>");
+    for column in table.header() {
+        html.push_str(&format!("<th>{}</th>", html_escape(column)));
+    }
+    html.push_str("</tr></thead><tbody>");
+    for row in &table.rows {
+        html.push_str(&format!("<tr><td>{}</td><td>{}</td>", html_escape(&row.model), row.try_index));
+        for mark in &row.marks {
+            html.push_str(&format!("<td>{}</td>", html_number(*mark)));
+        }
+        html.push_str(&format!(
+            "<td>{}</td><td>{}</td><td>{}</td></tr>",
+            html_number(Some(row.total())),
+            row.total_tokens.map(|t| t.to_string()).unwrap_or_default(),
+            row.cost.map(|c| format!("{:.4}", c)).unwrap_or_default(),
+        ));
+    }
+    html.push_str("</tbody></table></body></html>");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn response(json: &str) -> ResultsResponse {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn sample() -> ResultsTable {
+        ResultsTable::from_response(&response(
+            r#"{"results_by_question": {
+                "q10": {"model-b": [{"try_index": 1, "marks_awarded": 1.0}]},
+                "q2": {
+                    "model-b": [{"try_index": 1, "marks_awarded": 2.5,
+                                 "token_usage": {"total_tokens": 300, "cost_estimate": 0.01}}],
+                    "model-a": [{"try_index": 2, "marks_awarded": 4.0},
+                                {"try_index": 1, "marks_awarded": null}]
+                }
+            }}"#,
+        ))
+    }
+
+    #[test]
+    fn natural_cmp_orders_digit_runs_numerically() {
+        let mut questions = vec!["q10", "q2", "q1", "q02b", "Q3", "q2a"];
+        questions.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(questions, ["Q3", "q1", "q2", "q2a", "q02b", "q10"]);
+        assert_eq!(natural_cmp("q007", "q7"), Ordering::Equal);
+        assert_eq!(natural_cmp("", "q"), Ordering::Less);
+        assert_eq!(natural_cmp("résumé 2", "résumé 10"), Ordering::Less);
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn html_escape_covers_markup_characters() {
+        assert_eq!(html_escape(r#"<a href="x">&</a>"#), "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;");
+    }
+
+    #[test]
+    fn from_response_builds_one_row_per_attempt() {
+        let table = sample();
+        assert_eq!(table.questions, ["q2", "q10"]);
+        let rows: Vec<(&str, u32, Vec<Option<f64>>)> = table
+            .rows
+            .iter()
+            .map(|r| (r.model.as_str(), r.try_index, r.marks.clone()))
+            .collect();
+        assert_eq!(
+            rows,
+            [
+                ("model-a", 1, vec![None, None]),
+                ("model-a", 2, vec![Some(4.0), None]),
+                ("model-b", 1, vec![Some(2.5), Some(1.0)]),
+            ]
+        );
+        assert_eq!(table.rows[2].total_tokens, Some(300));
+        assert_eq!(table.rows[2].cost, Some(0.01));
+        assert_eq!(table.rows[0].total(), 0.0);
+        assert!(table.rows[0].total().is_sign_positive());
+    }
+
+    #[test]
+    fn model_summaries_aggregate_attempts() {
+        let table = sample();
+        let summaries = table.model_summaries();
+        assert_eq!(summaries.len(), 2);
+        let a = &summaries[0];
+        assert_eq!((a.model, a.tries, a.average, a.lowest, a.highest), ("model-a", 2, 2.0, 0.0, 4.0));
+        assert_eq!(a.total_tokens, None);
+        let b = &summaries[1];
+        assert_eq!((b.model, b.tries, b.average), ("model-b", 1, 3.5));
+        assert_eq!(b.total_tokens, Some(300));
+    }
+
+    #[test]
+    fn write_csv_matches_the_results_layout() {
+        let path = std::env::temp_dir().join(format!("swiftgrade-export-{}.csv", std::process::id()));
+        write_csv(&path, &sample()).unwrap();
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines[0], "Model,Try,q2,q10,Total,Tokens,Cost (USD)");
+        assert_eq!(lines[1], "model-a,1,,,0,,");
+        assert_eq!(lines[3], "model-b,1,2.5,1,3.5,300,0.01");
+    }
+
+    #[test]
+    fn write_xlsx_produces_both_sheets_with_escaped_text() {
+        let mut table = sample();
+        table.rows[0].model = "a<b & \"c\"".to_string();
+        let path = std::env::temp_dir().join(format!("swiftgrade-export-{}.xlsx", std::process::id()));
+        write_xlsx(&path, "session-1", &table).unwrap();
+
+        let mut archive = zip::ZipArchive::new(File::open(&path).unwrap()).unwrap();
+        let mut workbook = String::new();
+        archive.by_name("xl/workbook.xml").unwrap().read_to_string(&mut workbook).unwrap();
+        assert!(workbook.contains(r#"name="Results""#));
+        assert!(workbook.contains(r#"name="Summary""#));
+        let mut strings = String::new();
+        archive.by_name("xl/sharedStrings.xml").unwrap().read_to_string(&mut strings).unwrap();
+        assert!(strings.contains("a&lt;b &amp; \"c\""));
+        assert!(strings.contains("session-1"));
+        drop(archive);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,532 @@
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+pub const LOG_FILE_NAME: &str = "backend.log";
+
+// Rotate once the active file passes this size, keeping a few old generations
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+const MAX_ROTATED_LOGS: u32 = 3;
+
+pub fn log_dir(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("logs")
+}
+
+// Appends backend output to logs/backend.log, rotating to backend.log.1..N by size
+pub struct LogWriter {
+    path: PathBuf,
+    file: File,
+    len: u64,
+    max_bytes: u64,
+}
+
+impl LogWriter {
+    pub fn open(dir: &Path) -> io::Result<Self> {
+        Self::open_with_limit(dir, MAX_LOG_BYTES)
+    }
+
+    fn open_with_limit(dir: &Path, max_bytes: u64) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(LOG_FILE_NAME);
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let len = file.metadata()?.len();
+        Ok(Self { path, file, len, max_bytes })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn write_line(&mut self, line: &str) -> io::Result<()> {
+        if self.len >= self.max_bytes {
+            self.rotate()?;
+        }
+        writeln!(self.file, "{}", line)?;
+        self.len += line.len() as u64 + 1;
+        Ok(())
+    }
+
+    // Move the active file aside as backend-<stamp>.log and continue in a fresh one
+    pub fn archive(&mut self, stamp: u64) -> io::Result<PathBuf> {
+        self.file.flush()?;
+        let archived = archive_file(&self.path, stamp)?;
+        self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        self.len = 0;
+        Ok(archived)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        // Shift backend.log.N-1 -> backend.log.N, dropping the oldest generation
+        for i in (1..MAX_ROTATED_LOGS).rev() {
+            let from = rotated_path(&self.path, i);
+            if from.exists() {
+                fs::rename(&from, rotated_path(&self.path, i + 1))?;
+            }
+        }
+        fs::rename(&self.path, rotated_path(&self.path, 1))?;
+
+        self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        self.len = 0;
+        Ok(())
+    }
+}
+
+// Archive the active log in `dir` with no writer open, e.g. while the backend is stopped
+pub fn archive_log(dir: &Path, stamp: u64) -> io::Result<PathBuf> {
+    archive_file(&dir.join(LOG_FILE_NAME), stamp)
+}
+
+// Rotated generations move along, so an archived session stays complete
+fn archive_file(path: &Path, stamp: u64) -> io::Result<PathBuf> {
+    let archived = path.with_file_name(format!("{}{}.log", ARCHIVE_PREFIX, stamp));
+    fs::rename(path, &archived)?;
+    for i in 1..=MAX_ROTATED_LOGS {
+        let from = rotated_path(path, i);
+        if from.exists() {
+            fs::rename(&from, rotated_path(&archived, i))?;
+        }
+    }
+    Ok(archived)
+}
+
+const ARCHIVE_PREFIX: &str = "backend-";
+
+fn rotated_path(path: &Path, generation: u32) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(format!(".{}", generation));
+    PathBuf::from(name)
+}
+
+// Every log file on disk: the active log and then each archived session, each followed by
+// whichever of its rotated generations exist
+pub fn log_files(dir: &Path) -> Vec<PathBuf> {
+    list_sessions(dir, false)
+        .iter()
+        .flat_map(|session| session_parts(Path::new(&session.path)))
+        .collect()
+}
+
+// A session's file followed by its existing rotated generations, newest first
+fn session_parts(path: &Path) -> Vec<PathBuf> {
+    let mut files = vec![path.to_path_buf()];
+    files.extend((1..=MAX_ROTATED_LOGS).map(|i| rotated_path(path, i)));
+    files.into_iter().filter(|p| p.exists()).collect()
+}
+
+// One run of backend output: the active backend.log, or a backend-<stamp>.log archived
+// by rotate_logs. Rotated generations count as part of their session.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogSession {
+    pub path: String,
+    pub active: bool,
+    // None where the filesystem doesn't record creation times
+    pub started_ms: Option<u64>,
+    // None while the active session is still being written
+    pub ended_ms: Option<u64>,
+    pub size_bytes: u64,
+    pub parts: usize,
+}
+
+fn unix_ms(time: io::Result<std::time::SystemTime>) -> Option<u64> {
+    let since = time.ok()?.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(since.as_millis() as u64)
+}
+
+fn describe_session(path: &Path, active: bool, writing: bool) -> Option<LogSession> {
+    let parts = session_parts(path);
+    if parts.is_empty() {
+        return None;
+    }
+    let metadata: Vec<fs::Metadata> = parts.iter().filter_map(|p| fs::metadata(p).ok()).collect();
+    // The oldest generation holds the start of the session
+    let started_ms = metadata.last().and_then(|m| unix_ms(m.created()));
+    let archived_at = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_prefix(ARCHIVE_PREFIX)?.strip_suffix(".log")?.parse::<u64>().ok());
+    let ended_ms = match (active, writing) {
+        (true, true) => None,
+        _ => archived_at.or_else(|| metadata.first().and_then(|m| unix_ms(m.modified()))),
+    };
+    Some(LogSession {
+        path: path.to_string_lossy().to_string(),
+        active,
+        started_ms,
+        ended_ms,
+        size_bytes: metadata.iter().map(|m| m.len()).sum(),
+        parts: parts.len(),
+    })
+}
+
+// The active session first, then archived ones newest first. `writing` says whether the
+// backend is appending to the active log right now.
+pub fn list_sessions(dir: &Path, writing: bool) -> Vec<LogSession> {
+    let mut archived: Vec<LogSession> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with(ARCHIVE_PREFIX) && name.ends_with(".log"))
+                })
+                .filter_map(|path| describe_session(&path, false, false))
+                .collect()
+        })
+        .unwrap_or_default();
+    archived.sort_by_key(|session| std::cmp::Reverse(session.ended_ms));
+
+    describe_session(&dir.join(LOG_FILE_NAME), true, writing)
+        .into_iter()
+        .chain(archived)
+        .collect()
+}
+
+// Last `n` lines of a session, reaching into older generations when the newest is short
+pub fn read_session(path: &Path, n: usize) -> io::Result<Vec<String>> {
+    let mut lines: Vec<String> = Vec::new();
+    for part in session_parts(path) {
+        let missing = n.saturating_sub(lines.len());
+        if missing == 0 {
+            break;
+        }
+        let mut older = read_last_lines(&part, missing)?;
+        older.append(&mut lines);
+        lines = older;
+    }
+    Ok(lines)
+}
+
+// Read the last `n` lines of a file without loading more than needed from the end
+pub fn read_last_lines(path: &Path, n: usize) -> io::Result<Vec<String>> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    // Grow the window from the end until it holds enough lines or covers the file
+    let mut window: u64 = 64 * 1024;
+    loop {
+        let start = len.saturating_sub(window);
+        file.seek(SeekFrom::Start(start))?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+
+        let text = String::from_utf8_lossy(&buf);
+        let mut lines: Vec<&str> = text.lines().collect();
+
+        // The first line is likely partial unless we started at the top
+        if start > 0 && !lines.is_empty() {
+            lines.remove(0);
+        }
+
+        if lines.len() >= n || start == 0 {
+            let skip = lines.len().saturating_sub(n);
+            return Ok(lines[skip..].iter().map(|l| l.to_string()).collect());
+        }
+        window *= 4;
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogLine {
+    pub seq: u64,
+    pub stream: LogStream,
+    pub line: String,
+    pub timestamp_ms: u64,
+}
+
+// In-memory ring of recent backend output. Sequence numbers start at 1 and never
+// repeat, so consumers can ask for "everything after what I've seen".
+// Bounded by both line count and total line bytes; the newest line is always kept.
+pub struct LogBuffer {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+    max_bytes: usize,
+    bytes: usize,
+    // Lines pushed out by either limit since the buffer was created
+    evicted: u64,
+    next_seq: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogBufferUsage {
+    pub lines: usize,
+    pub bytes: usize,
+    pub max_lines: usize,
+    pub max_bytes: usize,
+    pub evicted: u64,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize, max_bytes: usize) -> Self {
+        Self {
+            lines: VecDeque::with_capacity(capacity),
+            capacity,
+            max_bytes,
+            bytes: 0,
+            evicted: 0,
+            next_seq: 1,
+        }
+    }
+
+    pub fn push(&mut self, stream: LogStream, line: &str, timestamp_ms: u64) -> u64 {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.bytes += line.len();
+        self.lines.push_back(LogLine {
+            seq,
+            stream,
+            line: line.to_string(),
+            timestamp_ms,
+        });
+        self.evict();
+        seq
+    }
+
+    fn evict(&mut self) {
+        while self.lines.len() > 1 && (self.lines.len() > self.capacity || self.bytes > self.max_bytes) {
+            if let Some(oldest) = self.lines.pop_front() {
+                self.bytes -= oldest.line.len();
+                self.evicted += 1;
+            }
+        }
+    }
+
+    // Apply new limits, evicting the oldest lines right away if they're now over
+    pub fn set_limits(&mut self, capacity: usize, max_bytes: usize) {
+        self.capacity = capacity;
+        self.max_bytes = max_bytes;
+        self.evict();
+    }
+
+    pub fn usage(&self) -> LogBufferUsage {
+        LogBufferUsage {
+            lines: self.lines.len(),
+            bytes: self.bytes,
+            max_lines: self.capacity,
+            max_bytes: self.max_bytes,
+            evicted: self.evicted,
+        }
+    }
+
+    // Sequence number of the newest line, or 0 when nothing has been logged
+    pub fn last_seq(&self) -> u64 {
+        self.next_seq - 1
+    }
+
+    pub fn recent(&self, limit: usize) -> Vec<LogLine> {
+        let skip = self.lines.len().saturating_sub(limit);
+        self.lines.iter().skip(skip).cloned().collect()
+    }
+
+    // Drop lines that fail `keep`, returning how many were removed. Sequence numbers are
+    // left alone so consumers tracking "after seq N" aren't confused.
+    pub fn retain(&mut self, mut keep: impl FnMut(&str) -> bool) -> usize {
+        let before = self.lines.len();
+        self.lines.retain(|l| keep(&l.line));
+        self.bytes = self.lines.iter().map(|l| l.line.len()).sum();
+        before - self.lines.len()
+    }
+
+    pub fn since(&self, seq: u64) -> Vec<LogLine> {
+        self.lines.iter().filter(|l| l.seq > seq).cloned().collect()
+    }
+}
+
+// Shell-side diagnostics on stdout/stderr, filtered by the runtime level in log::max_level().
+// Backend output is logged under the "backend" target so it can be told apart.
+struct ShellLogger;
+
+impl log::Log for ShellLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let prefix = if record.target() == "backend" { "backend " } else { "" };
+        match record.level() {
+            log::Level::Error | log::Level::Warn => eprintln!("[{}{}] {}", prefix, record.level(), record.args()),
+            _ => println!("[{}{}] {}", prefix, record.level(), record.args()),
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+pub const DEFAULT_LOG_LEVEL: log::LevelFilter = log::LevelFilter::Info;
+
+pub fn init_logger() {
+    // Only fails if a logger is already installed, which is harmless
+    let _ = log::set_logger(&ShellLogger);
+    log::set_max_level(DEFAULT_LOG_LEVEL);
+}
+
+pub fn parse_level(level: &str) -> Result<log::LevelFilter, String> {
+    level
+        .trim()
+        .parse()
+        .map_err(|_| format!("Unknown log level {:?}; expected off, error, warn, info, debug or trace", level))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("swiftgrade-logs-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn read(path: &Path) -> String {
+        fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn rotation_numbers_generations_and_drops_the_oldest() {
+        let dir = temp_dir("rotate");
+        // Each line fills the file, so every write after the first rotates
+        let mut writer = LogWriter::open_with_limit(&dir, 4).unwrap();
+        for line in ["one", "two", "three", "four", "five"] {
+            writer.write_line(line).unwrap();
+        }
+        let active = dir.join(LOG_FILE_NAME);
+        assert_eq!(read(&active), "five\n");
+        assert_eq!(read(&rotated_path(&active, 1)), "four\n");
+        assert_eq!(read(&rotated_path(&active, 2)), "three\n");
+        assert_eq!(read(&rotated_path(&active, MAX_ROTATED_LOGS)), "two\n");
+        assert!(!rotated_path(&active, MAX_ROTATED_LOGS + 1).exists(), "\"one\" is pruned");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reopening_continues_the_size_count() {
+        let dir = temp_dir("reopen");
+        LogWriter::open_with_limit(&dir, 8).unwrap().write_line("1234567").unwrap();
+        let mut writer = LogWriter::open_with_limit(&dir, 8).unwrap();
+        writer.write_line("next").unwrap();
+        assert_eq!(read(&rotated_path(&dir.join(LOG_FILE_NAME), 1)), "1234567\n");
+        assert_eq!(read(writer.path()), "next\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn archive_takes_the_rotated_generations_along() {
+        let dir = temp_dir("archive");
+        let mut writer = LogWriter::open_with_limit(&dir, 4).unwrap();
+        writer.write_line("old").unwrap();
+        writer.write_line("new").unwrap();
+
+        let archived = writer.archive(1_700_000_000_000).unwrap();
+        assert_eq!(archived, dir.join("backend-1700000000000.log"));
+        assert_eq!(read(&archived), "new\n");
+        assert_eq!(read(&rotated_path(&archived, 1)), "old\n");
+        assert!(!rotated_path(&dir.join(LOG_FILE_NAME), 1).exists());
+
+        writer.write_line("fresh").unwrap();
+        assert_eq!(read(writer.path()), "fresh\n");
+
+        let sessions = list_sessions(&dir, true);
+        assert_eq!(sessions.len(), 2);
+        assert!(sessions[0].active && sessions[0].ended_ms.is_none());
+        assert_eq!(sessions[1].ended_ms, Some(1_700_000_000_000));
+        assert_eq!(sessions[1].parts, 2);
+        assert_eq!(log_files(&dir).len(), 3);
+
+        drop(writer);
+        let again = archive_log(&dir, 1_700_000_000_001).unwrap();
+        assert_eq!(read(&again), "fresh\n");
+        assert!(!dir.join(LOG_FILE_NAME).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn session_tail_reaches_across_the_rotation_boundary() {
+        let dir = temp_dir("tail");
+        let mut writer = LogWriter::open_with_limit(&dir, 20).unwrap();
+        for i in 1..=6 {
+            writer.write_line(&format!("line {}", i)).unwrap();
+        }
+        // line 1-3 went to backend.log.1, 4-6 are in the active file
+        let active = dir.join(LOG_FILE_NAME);
+        assert_eq!(read_session(&active, 2).unwrap(), ["line 5", "line 6"]);
+        assert_eq!(read_session(&active, 4).unwrap(), ["line 3", "line 4", "line 5", "line 6"]);
+        assert_eq!(read_session(&active, 100).unwrap().len(), 6);
+        assert!(read_session(&active, 0).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn read_last_lines_grows_past_the_first_window() {
+        let dir = temp_dir("window");
+        let path = dir.join("big.log");
+        // About 100 KiB, so the 64 KiB window starts mid-line and has to widen
+        let content: String = (0..2000).map(|i| format!("{:04} {}\n", i, "x".repeat(45))).collect();
+        fs::write(&path, &content).unwrap();
+
+        let last = read_last_lines(&path, 3).unwrap();
+        assert_eq!(last.len(), 3);
+        assert!(last[2].starts_with("1999 "));
+        let all = read_last_lines(&path, 5000).unwrap();
+        assert_eq!(all.len(), 2000);
+        assert!(all[0].starts_with("0000 "));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn buffer_evicts_by_line_count_and_bytes() {
+        let mut buffer = LogBuffer::new(3, 1000);
+        for i in 1..=5 {
+            buffer.push(LogStream::Stdout, &format!("l{}", i), 0);
+        }
+        let seqs: Vec<u64> = buffer.recent(10).iter().map(|l| l.seq).collect();
+        assert_eq!(seqs, [3, 4, 5]);
+        assert_eq!(buffer.usage().evicted, 2);
+        assert_eq!(buffer.last_seq(), 5);
+
+        let mut buffer = LogBuffer::new(100, 10);
+        buffer.push(LogStream::Stdout, "aaaa", 0);
+        buffer.push(LogStream::Stderr, "bbbb", 0);
+        buffer.push(LogStream::Stdout, "cccc", 0);
+        assert_eq!(buffer.usage().lines, 2);
+        assert_eq!(buffer.usage().bytes, 8);
+
+        // A line over the byte limit on its own is still kept
+        buffer.push(LogStream::Stdout, &"z".repeat(50), 0);
+        assert_eq!(buffer.usage().lines, 1);
+        assert_eq!(buffer.since(0)[0].seq, 4);
+    }
+
+    #[test]
+    fn buffer_limits_apply_immediately_and_sequences_survive_retain() {
+        let mut buffer = LogBuffer::new(10, 1000);
+        for line in ["keep", "drop", "keep", "drop"] {
+            buffer.push(LogStream::Stdout, line, 0);
+        }
+        assert_eq!(buffer.retain(|line| line == "keep"), 2);
+        let seqs: Vec<u64> = buffer.since(0).iter().map(|l| l.seq).collect();
+        assert_eq!(seqs, [1, 3]);
+        assert_eq!(buffer.usage().bytes, 8);
+
+        buffer.set_limits(1, 1000);
+        assert_eq!(buffer.since(0)[0].seq, 3);
+        assert_eq!(buffer.since(3).len(), 0);
+        assert_eq!(buffer.push(LogStream::Stdout, "next", 0), 5);
+        assert_eq!(LogBuffer::new(1, 1).last_seq(), 0);
+    }
+}
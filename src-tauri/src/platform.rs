@@ -0,0 +1,310 @@
+use std::path::Path;
+use std::process::Command;
+
+fn spawn(command: &mut Command) -> Result<(), String> {
+    command.spawn().map(|_| ()).map_err(|e| e.to_string())
+}
+
+// Show a folder, or a file selected in its folder, in the OS file manager
+#[cfg(target_os = "windows")]
+pub fn reveal_in_file_manager(path: &Path) -> Result<(), String> {
+    if path.is_dir() {
+        spawn(Command::new("explorer").arg(path))
+    } else {
+        spawn(Command::new("explorer").arg(format!("/select,{}", path.display())))
+    }
+}
+
+#[cfg(target_os = "macos")]
+pub fn reveal_in_file_manager(path: &Path) -> Result<(), String> {
+    if path.is_dir() {
+        spawn(Command::new("open").arg(path))
+    } else {
+        spawn(Command::new("open").arg("-R").arg(path))
+    }
+}
+
+// Ask the desktop's file manager to select the file over D-Bus (Nautilus, Dolphin, Nemo...);
+// xdg-open has no "select this file", so without it files open their containing folder
+#[cfg(target_os = "linux")]
+pub fn reveal_in_file_manager(path: &Path) -> Result<(), String> {
+    if !path.is_dir() {
+        if let Ok(uri) = reqwest::Url::from_file_path(path) {
+            let selected = Command::new("dbus-send")
+                .args([
+                    "--session",
+                    "--dest=org.freedesktop.FileManager1",
+                    "--type=method_call",
+                    "/org/freedesktop/FileManager1",
+                    "org.freedesktop.FileManager1.ShowItems",
+                ])
+                .arg(format!("array:string:{}", uri))
+                .arg("string:")
+                .status()
+                .is_ok_and(|status| status.success());
+            if selected {
+                return Ok(());
+            }
+        }
+    }
+    let dir = if path.is_dir() { path } else { path.parent().unwrap_or(path) };
+    spawn(Command::new("xdg-open").arg(dir))
+}
+
+#[cfg(target_os = "windows")]
+pub fn open_in_text_editor(path: &Path) -> Result<(), String> {
+    spawn(Command::new("notepad").arg(path))
+}
+
+#[cfg(target_os = "macos")]
+pub fn open_in_text_editor(path: &Path) -> Result<(), String> {
+    spawn(Command::new("open").arg("-t").arg(path))
+}
+
+#[cfg(target_os = "linux")]
+pub fn open_in_text_editor(path: &Path) -> Result<(), String> {
+    spawn(Command::new("xdg-open").arg(path))
+}
+
+// Open a file with its default application
+#[cfg(target_os = "windows")]
+pub fn open_file(path: &Path) -> Result<(), String> {
+    spawn(Command::new("explorer").arg(path))
+}
+
+#[cfg(target_os = "macos")]
+pub fn open_file(path: &Path) -> Result<(), String> {
+    spawn(Command::new("open").arg(path))
+}
+
+#[cfg(target_os = "linux")]
+pub fn open_file(path: &Path) -> Result<(), String> {
+    spawn(Command::new("xdg-open").arg(path))
+}
+
+// Open a URL in the default browser
+#[cfg(target_os = "windows")]
+pub fn open_url(url: &str) -> Result<(), String> {
+    spawn(Command::new("rundll32").args(["url.dll,FileProtocolHandler", url]))
+}
+
+#[cfg(target_os = "macos")]
+pub fn open_url(url: &str) -> Result<(), String> {
+    spawn(Command::new("open").arg(url))
+}
+
+#[cfg(target_os = "linux")]
+pub fn open_url(url: &str) -> Result<(), String> {
+    spawn(Command::new("xdg-open").arg(url))
+}
+
+// Run a capture tool to completion, surfacing its stderr when it fails
+fn run(command: &mut Command) -> Result<(), String> {
+    let output = command.output().map_err(|e| e.to_string())?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
+
+// An on-screen rectangle in physical pixels, plus the display's scale factor
+#[derive(Debug, Clone, Copy)]
+pub struct ScreenRegion {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    // Only macOS's tool wants logical coordinates
+    #[cfg_attr(not(target_os = "macos"), allow(dead_code))]
+    pub scale: f64,
+}
+
+// Save what is on screen inside `region` as a PNG. The window has to be visible and
+// unobscured, since this copies the screen rather than the window's own buffer.
+#[cfg(target_os = "windows")]
+pub fn capture_screen_region(region: ScreenRegion, path: &Path) -> Result<(), String> {
+    let target = path.display().to_string().replace('\'', "''");
+    let script = format!(
+        "Add-Type -AssemblyName System.Drawing; \
+         Add-Type -Name Dpi -Namespace Swiftgrade -MemberDefinition '[DllImport(\"user32.dll\")] public static extern bool SetProcessDPIAware();'; \
+         [void][Swiftgrade.Dpi]::SetProcessDPIAware(); \
+         $bitmap = New-Object System.Drawing.Bitmap {w}, {h}; \
+         $graphics = [System.Drawing.Graphics]::FromImage($bitmap); \
+         $graphics.CopyFromScreen({x}, {y}, 0, 0, $bitmap.Size); \
+         $bitmap.Save('{target}', [System.Drawing.Imaging.ImageFormat]::Png)",
+        x = region.x,
+        y = region.y,
+        w = region.width,
+        h = region.height,
+    );
+    run(Command::new("powershell").args(["-NoProfile", "-NonInteractive", "-Command", &script]))
+}
+
+// screencapture takes the rectangle in points, not pixels
+#[cfg(target_os = "macos")]
+pub fn capture_screen_region(region: ScreenRegion, path: &Path) -> Result<(), String> {
+    let points = |v: f64| (v / region.scale).round() as i64;
+    let rect = format!(
+        "{},{},{},{}",
+        points(region.x as f64),
+        points(region.y as f64),
+        points(region.width as f64),
+        points(region.height as f64),
+    );
+    run(Command::new("screencapture").args(["-x", "-t", "png", "-R", &rect]).arg(path))
+}
+
+// grim on Wayland, ImageMagick's import on X11
+#[cfg(target_os = "linux")]
+pub fn capture_screen_region(region: ScreenRegion, path: &Path) -> Result<(), String> {
+    let geometry = format!("{},{} {}x{}", region.x, region.y, region.width, region.height);
+    let grim = run(Command::new("grim").args(["-g", &geometry]).arg(path));
+    if grim.is_ok() {
+        return grim;
+    }
+    let crop = format!("{}x{}+{}+{}", region.width, region.height, region.x, region.y);
+    run(Command::new("import").args(["-window", "root", "-crop", &crop]).arg(path)).map_err(|e| {
+        format!("no screenshot tool worked (install grim or ImageMagick): {}", e)
+    })
+}
+
+// Whether this platform's webview can be told to skip the GPU
+pub const CAN_DISABLE_HARDWARE_ACCELERATION: bool = cfg!(any(target_os = "windows", target_os = "linux"));
+
+// Turn off GPU rendering for webviews created after this call. Environment variables
+// are read when the webview starts, so this has to run before the first window exists.
+#[cfg(target_os = "windows")]
+pub fn disable_hardware_acceleration() {
+    const VAR: &str = "WEBVIEW2_ADDITIONAL_BROWSER_ARGUMENTS";
+    let args = match std::env::var(VAR) {
+        Ok(existing) if !existing.trim().is_empty() => format!("{} --disable-gpu", existing),
+        _ => "--disable-gpu".to_string(),
+    };
+    std::env::set_var(VAR, args);
+}
+
+#[cfg(target_os = "linux")]
+pub fn disable_hardware_acceleration() {
+    std::env::set_var("WEBKIT_DISABLE_COMPOSITING_MODE", "1");
+    std::env::set_var("WEBKIT_DISABLE_DMABUF_RENDERER", "1");
+}
+
+// WKWebView has no switch for this
+#[cfg(target_os = "macos")]
+pub fn disable_hardware_acceleration() {}
+
+// Whether Windows Defender real-time protection is on; None when it can't be queried
+// (another antivirus replaced it, or this isn't Windows)
+#[cfg(target_os = "windows")]
+pub fn defender_realtime_protection() -> Option<bool> {
+    let output = Command::new("powershell")
+        .args(["-NoProfile", "-NonInteractive", "-Command", "(Get-MpComputerStatus).RealTimeProtectionEnabled"])
+        .output()
+        .ok()?;
+    match String::from_utf8_lossy(&output.stdout).trim() {
+        "True" => Some(true),
+        "False" => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn defender_realtime_protection() -> Option<bool> {
+    None
+}
+
+// Whether cap_process_memory can enforce anything on this OS
+pub const PROCESS_MEMORY_CAP_SUPPORTED: bool = cfg!(target_os = "windows");
+
+// Cap the committed memory of `pid` and every process it starts from now on, by putting it
+// in a Job Object. Windows only: RLIMIT_AS elsewhere limits address space rather than
+// memory actually used, which breaks allocators that reserve large regions up front.
+#[cfg(target_os = "windows")]
+pub fn cap_process_memory(pid: u32, limit_bytes: u64) -> Result<(), String> {
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation, SetInformationJobObject,
+        JOBOBJECT_EXTENDED_LIMIT_INFORMATION, JOB_OBJECT_LIMIT_JOB_MEMORY,
+    };
+    use windows_sys::Win32::System::Threading::{OpenProcess, PROCESS_SET_QUOTA, PROCESS_TERMINATE};
+    
+    let last_error = || std::io::Error::last_os_error().to_string();
+    // SAFETY: plain Win32 calls on handles we own and close before returning; the job lives
+    // on after its handle is closed for as long as a process is assigned to it
+    unsafe {
+        let process = OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE, 0, pid);
+        if process.is_null() {
+            return Err(format!("Failed to open process {}: {}", pid, last_error()));
+        }
+        let job = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+        if job.is_null() {
+            let error = last_error();
+            CloseHandle(process);
+            return Err(format!("Failed to create a job object: {}", error));
+        }
+        
+        let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+        info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_JOB_MEMORY;
+        info.JobMemoryLimit = limit_bytes as usize;
+        let result = if SetInformationJobObject(
+            job,
+            JobObjectExtendedLimitInformation,
+            &info as *const _ as *const std::ffi::c_void,
+            std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+        ) == 0
+        {
+            Err(format!("Failed to set the job's memory limit: {}", last_error()))
+        } else if AssignProcessToJobObject(job, process) == 0 {
+            Err(format!("Failed to assign process {} to the job: {}", pid, last_error()))
+        } else {
+            Ok(())
+        };
+        CloseHandle(job);
+        CloseHandle(process);
+        result
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn cap_process_memory(_pid: u32, _limit_bytes: u64) -> Result<(), String> {
+    Err("Memory caps are only supported on Windows".to_string())
+}
+
+// (pid, parent pid, resident bytes) for every process
+#[cfg(target_os = "windows")]
+pub fn process_memory_table() -> Vec<(u32, u32, u64)> {
+    let Ok(output) = Command::new("powershell")
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            "Get-CimInstance Win32_Process | ForEach-Object { \"$($_.ProcessId),$($_.ParentProcessId),$($_.WorkingSetSize)\" }",
+        ])
+        .output()
+    else {
+        return Vec::new();
+    };
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let mut cols = line.trim().split(',').map(|c| c.parse::<u64>().ok());
+            Some((cols.next()?? as u32, cols.next()?? as u32, cols.next()??))
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "windows"))]
+pub fn process_memory_table() -> Vec<(u32, u32, u64)> {
+    // rss is in KiB on both Linux and macOS
+    let Ok(output) = Command::new("ps").args(["-axo", "pid=,ppid=,rss="]).output() else {
+        return Vec::new();
+    };
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let mut cols = line.split_whitespace().map(|c| c.parse::<u64>().ok());
+            Some((cols.next()?? as u32, cols.next()?? as u32, cols.next()?? * 1024))
+        })
+        .collect()
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const PREFS_FILE_NAME: &str = "preferences.json";
+
+// Non-secret app preferences persisted alongside the .env in the app data dir
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub preferred_port: Option<u16>,
+    // Last size/position per window label, in logical pixels
+    pub windows: HashMap<String, WindowGeometry>,
+    // Selected profile (class/institution); None means the global settings apply
+    pub active_profile: Option<String>,
+    pub profiles: HashMap<String, Profile>,
+    // Keep the backend's access-log lines for /health polls instead of filtering them out
+    pub show_health_check_logs: bool,
+    pub backend_mode: BackendMode,
+    // Show the main window immediately and start the backend behind it
+    pub background_start: bool,
+    // Rust-side log level (off/error/warn/info/debug/trace); None means the default
+    pub log_level: Option<String>,
+    // Active data dir namespace (see SWIFTGRADE_ENV); None is the default environment
+    pub environment: Option<String>,
+    pub auto_restart: AutoRestartPolicy,
+    // Render without the GPU from the next launch, for driver-related display glitches
+    pub disable_hardware_acceleration: bool,
+    // Reopen the floating backend log overlay on launch
+    pub log_overlay_visible: bool,
+    // In-memory backend log limits; None keeps the defaults
+    pub log_buffer_lines: Option<usize>,
+    pub log_buffer_bytes: Option<usize>,
+    // Log the full sidecar launch on every spawn, for diagnosing startup failures
+    pub verbose_spawn: bool,
+}
+
+// Restart a long-running bundled backend on a schedule, never while grading
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AutoRestartPolicy {
+    #[default]
+    Disabled,
+    // After this long with no grading run
+    Idle { minutes: u32 },
+    // Every day at this local time, "HH:MM"
+    Daily { time: String },
+}
+
+// Run the bundled sidecar, or talk to a backend the developer started themselves
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BackendMode {
+    #[default]
+    Bundled,
+    External { url: String },
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub preferred_port: Option<u16>,
+}
+
+impl Preferences {
+    // A profile only uses its own port, so switching profiles is deterministic
+    pub fn preferred_port(&self) -> Option<u16> {
+        match &self.active_profile {
+            Some(name) => self.profiles.get(name).and_then(|p| p.preferred_port),
+            None => self.preferred_port,
+        }
+    }
+
+    pub fn set_preferred_port(&mut self, port: u16) {
+        match self.active_profile.clone() {
+            Some(name) => self.profiles.entry(name).or_default().preferred_port = Some(port),
+            None => self.preferred_port = Some(port),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct WindowGeometry {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+pub fn prefs_path(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join(PREFS_FILE_NAME)
+}
+
+// Missing or unreadable prefs fall back to defaults rather than blocking startup
+pub fn load(app_data_dir: &Path) -> Preferences {
+    fs::read_to_string(prefs_path(app_data_dir))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(app_data_dir: &Path, prefs: &Preferences) -> io::Result<()> {
+    fs::create_dir_all(app_data_dir)?;
+    let content = serde_json::to_string_pretty(prefs).map_err(io::Error::other)?;
+    fs::write(prefs_path(app_data_dir), content)
+}
+
+// Bump when the export file changes incompatibly; older exports stay importable
+pub const EXPORT_VERSION: u32 = 1;
+
+// Portable copy of the preferences for moving to another machine. Holds no secrets:
+// credentials live in the .env and move through the config import instead.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PreferencesExport {
+    pub version: u32,
+    pub app_version: String,
+    pub preferences: Preferences,
+}
+
+pub fn export_to(path: &Path, prefs: &Preferences, app_version: &str) -> io::Result<()> {
+    let export = PreferencesExport {
+        version: EXPORT_VERSION,
+        app_version: app_version.to_string(),
+        preferences: prefs.clone(),
+    };
+    let content = serde_json::to_string_pretty(&export).map_err(io::Error::other)?;
+    fs::write(path, content)
+}
+
+// Unknown keys are ignored so exports from newer builds still load, but a newer
+// format version is refused rather than half-applied
+pub fn import_from(content: &str) -> Result<Preferences, String> {
+    let value: serde_json::Value = serde_json::from_str(content)
+        .map_err(|e| format!("Not a valid preferences file: {}", e))?;
+    let version = value
+        .get("version")
+        .and_then(|v| v.as_u64())
+        .ok_or("Not a Swiftgrade preferences export (missing version)")?;
+    if version > EXPORT_VERSION as u64 {
+        return Err(format!(
+            "These preferences were exported by a newer version of Swiftgrade (format {}); update the app to import them",
+            version
+        ));
+    }
+    let export: PreferencesExport = serde_json::from_value(value)
+        .map_err(|e| format!("Preferences file is damaged: {}", e))?;
+    Ok(export.preferences)
+}
@@ -0,0 +1,167 @@
+use regex::Regex;
+use serde::Serialize;
+use std::time::Instant;
+
+// Matches lines like "Graded 12/50" or "graded 12 / 50 submissions"
+pub const DEFAULT_PROGRESS_PATTERN: &str = r"(?i)\bgraded\s+(?P<current>\d+)\s*/\s*(?P<total>\d+)";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GradingStatus {
+    pub current: u64,
+    pub total: u64,
+    // Items per minute since this run's first progress line; None until there are two
+    pub rate_per_min: Option<f64>,
+    pub eta_secs: Option<u64>,
+}
+
+// Derives a progress indicator from plain backend output, for when the structured
+// endpoints aren't available. Lines that don't match are simply ignored.
+pub struct ProgressParser {
+    pattern: Regex,
+    run_start: Option<(Instant, u64)>,
+    last: Option<GradingStatus>,
+}
+
+impl ProgressParser {
+    // A custom pattern needs `current` and `total` named groups, or two plain groups in
+    // that order; anything unusable falls back to the default with the reason returned
+    pub fn new(custom: Option<&str>) -> (Self, Option<String>) {
+        let mut warning = None;
+        let pattern = match custom.map(Regex::new) {
+            Some(Ok(re)) if re.captures_len() >= 3 => re,
+            Some(Ok(_)) => {
+                warning = Some("pattern needs two capture groups (current and total)".to_string());
+                Self::default_pattern()
+            }
+            Some(Err(e)) => {
+                warning = Some(e.to_string());
+                Self::default_pattern()
+            }
+            None => Self::default_pattern(),
+        };
+        (Self { pattern, run_start: None, last: None }, warning)
+    }
+
+    fn default_pattern() -> Regex {
+        Regex::new(DEFAULT_PROGRESS_PATTERN).expect("default progress pattern is valid")
+    }
+
+    // Returns the new status when `line` reports progress
+    pub fn feed(&mut self, line: &str) -> Option<GradingStatus> {
+        let captures = self.pattern.captures(line)?;
+        let group = |name: &str, index: usize| {
+            captures
+                .name(name)
+                .or_else(|| captures.get(index))
+                .and_then(|m| m.as_str().parse::<u64>().ok())
+        };
+        let (current, total) = (group("current", 1)?, group("total", 2)?);
+        if total == 0 || current > total {
+            return None;
+        }
+
+        // A smaller count or a different total means a new run has started
+        let new_run = match &self.last {
+            Some(last) => current < last.current || total != last.total,
+            None => true,
+        };
+        let now = Instant::now();
+        if new_run {
+            self.run_start = Some((now, current));
+        }
+
+        let (rate_per_min, eta_secs) = match self.run_start {
+            Some((started, from)) if current > from => {
+                let elapsed = now.duration_since(started).as_secs_f64();
+                let per_sec = (current - from) as f64 / elapsed.max(0.001);
+                let eta = ((total - current) as f64 / per_sec).round() as u64;
+                (Some(per_sec * 60.0), Some(eta))
+            }
+            _ => (None, None),
+        };
+
+        let status = GradingStatus { current, total, rate_per_min, eta_secs };
+        self.last = Some(status.clone());
+        Some(status)
+    }
+
+    pub fn last(&self) -> Option<GradingStatus> {
+        self.last.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parser() -> ProgressParser {
+        ProgressParser::new(None).0
+    }
+
+    #[test]
+    fn well_formed_lines_report_progress() {
+        let mut parser = parser();
+        let status = parser.feed("INFO: Graded 12/50 submissions").unwrap();
+        assert_eq!((status.current, status.total), (12, 50));
+        assert!(status.rate_per_min.is_none(), "one line gives no rate yet");
+        let status = parser.feed("graded 13 / 50").unwrap();
+        assert_eq!((status.current, status.total), (13, 50));
+        assert!(status.rate_per_min.is_some_and(|rate| rate > 0.0));
+        assert!(status.eta_secs.is_some());
+        assert_eq!(parser.last().unwrap().current, 13);
+    }
+
+    #[test]
+    fn partial_and_garbage_lines_are_ignored() {
+        let mut parser = parser();
+        for line in [
+            "",
+            "Graded 12/",
+            "Graded /50",
+            "Ungraded 1/2",
+            "Graded twelve/fifty",
+            "Graded 3/0",
+            "Graded 51/50",
+            "Graded 99999999999999999999999/99999999999999999999999",
+            "\u{fffd}\u{0}Graded",
+        ] {
+            assert!(parser.feed(line).is_none(), "{:?}", line);
+        }
+        assert!(parser.last().is_none());
+    }
+
+    #[test]
+    fn counts_going_backwards_start_a_new_run() {
+        let mut parser = parser();
+        parser.feed("Graded 40/50");
+        assert!(parser.feed("Graded 45/50").unwrap().rate_per_min.is_some());
+
+        let restarted = parser.feed("Graded 2/50").unwrap();
+        assert_eq!(restarted.current, 2);
+        assert!(restarted.rate_per_min.is_none() && restarted.eta_secs.is_none());
+
+        // So does a different total at the same count
+        parser.feed("Graded 3/50");
+        assert!(parser.feed("Graded 3/20").unwrap().rate_per_min.is_none());
+        // A repeated count keeps the run but gains no rate
+        assert!(parser.feed("Graded 3/20").unwrap().rate_per_min.is_none());
+    }
+
+    #[test]
+    fn custom_patterns_fall_back_with_a_reason() {
+        let (mut parser, warning) = ProgressParser::new(Some(r"(\d+) of (\d+) done"));
+        assert!(warning.is_none());
+        assert_eq!(parser.feed("7 of 9 done").unwrap().current, 7);
+
+        let (mut parser, warning) = ProgressParser::new(Some(r"(?P<total>\d+) left, (?P<current>\d+) done"));
+        assert!(warning.is_none());
+        assert_eq!(parser.feed("9 left, 2 done").map(|s| (s.current, s.total)), Some((2, 9)));
+
+        let (mut parser, warning) = ProgressParser::new(Some(r"only (\d+)"));
+        assert!(warning.unwrap().contains("two capture groups"));
+        assert!(parser.feed("Graded 1/2").is_some(), "falls back to the default pattern");
+
+        let (_, warning) = ProgressParser::new(Some("(unclosed"));
+        assert!(warning.is_some());
+    }
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const RECENT_FILE_NAME: &str = "recent_exports.json";
+pub const MAX_RECENT_EXPORTS: usize = 10;
+
+// A file the user saved from the app, newest first in the list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentExport {
+    pub path: String,
+    pub exported_ms: u64,
+    pub job_id: Option<String>,
+}
+
+fn recent_path(data_dir: &Path) -> PathBuf {
+    data_dir.join(RECENT_FILE_NAME)
+}
+
+// Entries whose file has since been moved or deleted are left out
+pub fn load(data_dir: &Path) -> Vec<RecentExport> {
+    fs::read_to_string(recent_path(data_dir))
+        .ok()
+        .and_then(|content| serde_json::from_str::<Vec<RecentExport>>(&content).ok())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|entry| Path::new(&entry.path).is_file())
+        .collect()
+}
+
+fn save(data_dir: &Path, entries: &[RecentExport]) -> io::Result<()> {
+    fs::create_dir_all(data_dir)?;
+    let content = serde_json::to_string_pretty(entries).map_err(io::Error::other)?;
+    fs::write(recent_path(data_dir), content)
+}
+
+// Put `entry` at the front, replacing an older export to the same path
+pub fn record(data_dir: &Path, entry: RecentExport) -> io::Result<Vec<RecentExport>> {
+    let mut entries = load(data_dir);
+    entries.retain(|existing| existing.path != entry.path);
+    entries.insert(0, entry);
+    entries.truncate(MAX_RECENT_EXPORTS);
+    save(data_dir, &entries)?;
+    Ok(entries)
+}
@@ -0,0 +1,143 @@
+use serde::Serialize;
+
+// How a setting is edited and validated in the settings form
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FieldType {
+    Text,
+    Url,
+    Bool,
+    Integer { min: u64, max: u64 },
+    // A request path on the backend, e.g. "/shutdown"
+    Path,
+    Regex,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigField {
+    pub key: &'static str,
+    #[serde(flatten)]
+    pub field_type: FieldType,
+    pub default: Option<&'static str>,
+    pub secret: bool,
+    pub required: bool,
+    pub description: &'static str,
+}
+
+const fn field(key: &'static str, field_type: FieldType, description: &'static str) -> ConfigField {
+    ConfigField { key, field_type, default: None, secret: false, required: false, description }
+}
+
+// Every .env key the app knows about, in the order the settings form shows them
+pub const CONFIG_SCHEMA: &[ConfigField] = &[
+    ConfigField {
+        secret: true,
+        required: true,
+        ..field("OPENROUTER_API_KEY", FieldType::Text, "OpenRouter API key used for grading requests")
+    },
+    ConfigField {
+        required: true,
+        ..field("SUPABASE_URL", FieldType::Url, "Supabase project URL, e.g. https://<project>.supabase.co")
+    },
+    ConfigField {
+        secret: true,
+        required: true,
+        ..field("SUPABASE_SERVICE_ROLE_KEY", FieldType::Text, "Supabase service-role key (not the anon key)")
+    },
+    ConfigField {
+        default: Some("grading-images"),
+        ..field("SUPABASE_STORAGE_BUCKET", FieldType::Text, "Storage bucket for uploaded answer images")
+    },
+    field("OPENROUTER_MODEL", FieldType::Text, "Default model when an assessment doesn't pick one"),
+    ConfigField {
+        default: Some("0"),
+        ..field("OPENROUTER_DEBUG", FieldType::Bool, "Log full OpenRouter requests and responses")
+    },
+    ConfigField {
+        default: Some("4"),
+        ..field("GRADING_MAX_CONCURRENCY", FieldType::Integer { min: 1, max: 32 }, "Grading requests sent in parallel")
+    },
+    field(
+        "BACKEND_MEMORY_LIMIT_MB",
+        FieldType::Integer { min: 0, max: 1024 * 1024 },
+        "Memory cap for the backend in MB (Windows only); 0 or empty for no limit",
+    ),
+    field(
+        "BACKEND_MEMORY_WARNING_MB",
+        FieldType::Integer { min: 64, max: 1024 * 1024 },
+        "Warn when the backend uses this much memory while starting (default 90% of the memory cap, else 2048)",
+    ),
+    field(
+        "BACKEND_STARTUP_DELAY_MS",
+        FieldType::Integer { min: 0, max: 120_000 },
+        "Wait this long before starting the backend, for slow network drives",
+    ),
+    field(
+        "BACKEND_WORKING_DIR",
+        FieldType::Text,
+        "Absolute folder the backend runs in, for builds that resolve relative paths; empty for the default",
+    ),
+    field("BACKEND_WARMUP_PATH", FieldType::Path, "Backend path requested once it is up, to prime caches"),
+    field("BACKEND_GRACEFUL_SHUTDOWN", FieldType::Bool, "Ask the backend to exit over HTTP before killing it"),
+    field("BACKEND_SHUTDOWN_PATH", FieldType::Path, "Endpoint for graceful shutdown (default /shutdown)"),
+    ConfigField {
+        default: Some("60000"),
+        ..field(
+            "BACKEND_REQUEST_TIMEOUT_MS",
+            FieldType::Integer { min: 1_000, max: 600_000 },
+            "How long backend requests (results, downloads) may take before giving up",
+        )
+    },
+    field(
+        "SKIP_SIDECAR_INTEGRITY",
+        FieldType::Bool,
+        "Start the backend even if it doesn't match the hash this build expects, for locally rebuilt backends",
+    ),
+    field("HTTP_PROXY", FieldType::Url, "Proxy for plain-HTTP requests to OpenRouter and Supabase"),
+    field("HTTPS_PROXY", FieldType::Url, "Proxy for HTTPS requests to OpenRouter and Supabase"),
+    field(
+        "NO_PROXY",
+        FieldType::Text,
+        "Comma-separated hosts that bypass the proxy; localhost is always included",
+    ),
+    field(
+        "GRADING_PROGRESS_PATTERN",
+        FieldType::Regex,
+        "Pattern for progress lines in backend output, with current and total groups",
+    ),
+];
+
+pub fn config_field(key: &str) -> Option<&'static ConfigField> {
+    CONFIG_SCHEMA.iter().find(|f| f.key == key)
+}
+
+// Check a non-empty value against its field and return it in the form it's stored
+pub fn validate(field: &ConfigField, value: &str) -> Result<String, String> {
+    let value = value.trim();
+    if value.contains(['\n', '\r']) {
+        return Err(format!("{} must be a single line", field.key));
+    }
+    match field.field_type {
+        FieldType::Text => Ok(value.to_string()),
+        FieldType::Url => match reqwest::Url::parse(value) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.host_str().is_some() => {
+                Ok(value.trim_end_matches('/').to_string())
+            }
+            _ => Err(format!("{} must be an http:// or https:// URL", field.key)),
+        },
+        FieldType::Bool => match value.to_ascii_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => Ok("1".to_string()),
+            "0" | "false" | "no" | "off" => Ok("0".to_string()),
+            _ => Err(format!("{} must be true or false", field.key)),
+        },
+        FieldType::Integer { min, max } => match value.parse::<u64>() {
+            Ok(n) if (min..=max).contains(&n) => Ok(n.to_string()),
+            _ => Err(format!("{} must be a whole number from {} to {}", field.key, min, max)),
+        },
+        FieldType::Path if value.starts_with('/') && !value.contains(char::is_whitespace) => Ok(value.to_string()),
+        FieldType::Path => Err(format!("{} must be a path starting with '/'", field.key)),
+        FieldType::Regex => regex::Regex::new(value)
+            .map(|_| value.to_string())
+            .map_err(|e| format!("{} is not a valid pattern: {}", field.key, e)),
+    }
+}
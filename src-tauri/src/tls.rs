@@ -0,0 +1,217 @@
+use chrono::{NaiveDate, NaiveDateTime, Utc};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CertificateInfo {
+    pub subject: String,
+    pub issuer: String,
+    // UTC, as "YYYY-MM-DDTHH:MM:SSZ"
+    pub not_before: String,
+    pub not_after: String,
+    // Negative once expired
+    pub expires_in_days: i64,
+    pub sha256_fingerprint: String,
+}
+
+// One DER element: (tag, contents, remaining input). Only the single-byte tags that
+// X.509 uses are handled.
+fn read_tlv(input: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let (&tag, rest) = input.split_first()?;
+    let (&first, rest) = rest.split_first()?;
+    let (len, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else {
+        let count = (first & 0x7f) as usize;
+        if count == 0 || count > 4 || rest.len() < count {
+            return None;
+        }
+        let len = rest[..count].iter().fold(0usize, |acc, &b| (acc << 8) | b as usize);
+        (len, &rest[count..])
+    };
+    if rest.len() < len {
+        return None;
+    }
+    Some((tag, &rest[..len], &rest[len..]))
+}
+
+const SEQUENCE: u8 = 0x30;
+const SET: u8 = 0x31;
+const OID: u8 = 0x06;
+const UTC_TIME: u8 = 0x17;
+const GENERALIZED_TIME: u8 = 0x18;
+const BMP_STRING: u8 = 0x1e;
+
+fn attribute_name(oid: &[u8]) -> Option<&'static str> {
+    match oid {
+        [0x55, 0x04, 0x03] => Some("CN"),
+        [0x55, 0x04, 0x06] => Some("C"),
+        [0x55, 0x04, 0x07] => Some("L"),
+        [0x55, 0x04, 0x08] => Some("ST"),
+        [0x55, 0x04, 0x0a] => Some("O"),
+        [0x55, 0x04, 0x0b] => Some("OU"),
+        _ => None,
+    }
+}
+
+fn decode_string(tag: u8, bytes: &[u8]) -> String {
+    if tag == BMP_STRING {
+        let units: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_be_bytes([c[0], c[1]])).collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        String::from_utf8_lossy(bytes).to_string()
+    }
+}
+
+// Render a Name as "CN=..., O=..., C=...", skipping attributes we don't name
+fn format_name(mut rdns: &[u8]) -> String {
+    let mut parts = Vec::new();
+    while let Some((SET, mut set, rest)) = read_tlv(rdns) {
+        rdns = rest;
+        while let Some((SEQUENCE, attribute, next)) = read_tlv(set) {
+            set = next;
+            let Some((OID, oid, value)) = read_tlv(attribute) else {
+                continue;
+            };
+            if let (Some(name), Some((tag, value, _))) = (attribute_name(oid), read_tlv(value)) {
+                parts.push(format!("{}={}", name, decode_string(tag, value)));
+            }
+        }
+    }
+    parts.join(", ")
+}
+
+fn parse_time(tag: u8, bytes: &[u8]) -> Option<NaiveDateTime> {
+    let text = std::str::from_utf8(bytes).ok()?.strip_suffix('Z')?;
+    let (year, rest) = match tag {
+        // Two-digit years: 50-99 are 19xx, per RFC 5280
+        UTC_TIME => {
+            let yy: i32 = text.get(..2)?.parse().ok()?;
+            (if yy >= 50 { 1900 + yy } else { 2000 + yy }, text.get(2..)?)
+        }
+        GENERALIZED_TIME => (text.get(..4)?.parse().ok()?, text.get(4..)?),
+        _ => return None,
+    };
+    let field = |i: usize| rest.get(i..i + 2).and_then(|s| s.parse::<u32>().ok());
+    NaiveDate::from_ymd_opt(year, field(0)?, field(2)?)?.and_hms_opt(field(4)?, field(6)?, field(8).unwrap_or(0))
+}
+
+// Pull the fields worth showing out of a leaf certificate. None when the DER doesn't
+// look like an X.509 certificate.
+pub fn parse_certificate(der: &[u8]) -> Option<CertificateInfo> {
+    let (SEQUENCE, certificate, _) = read_tlv(der)? else {
+        return None;
+    };
+    let (SEQUENCE, mut tbs, _) = read_tlv(certificate)? else {
+        return None;
+    };
+
+    // Optional [0] version, then serial number and signature algorithm
+    let (tag, _, rest) = read_tlv(tbs)?;
+    if tag == 0xa0 {
+        tbs = rest;
+        tbs = read_tlv(tbs)?.2;
+    } else {
+        tbs = rest;
+    }
+    tbs = read_tlv(tbs)?.2;
+
+    let (SEQUENCE, issuer, rest) = read_tlv(tbs)? else {
+        return None;
+    };
+    let (SEQUENCE, validity, rest) = read_tlv(rest)? else {
+        return None;
+    };
+    let (SEQUENCE, subject, _) = read_tlv(rest)? else {
+        return None;
+    };
+
+    let (tag, not_before, rest) = read_tlv(validity)?;
+    let not_before = parse_time(tag, not_before)?;
+    let (tag, not_after, _) = read_tlv(rest)?;
+    let not_after = parse_time(tag, not_after)?;
+
+    let timestamp = |t: NaiveDateTime| t.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+    Some(CertificateInfo {
+        subject: format_name(subject),
+        issuer: format_name(issuer),
+        not_before: timestamp(not_before),
+        not_after: timestamp(not_after),
+        expires_in_days: (not_after - Utc::now().naive_utc()).num_days(),
+        sha256_fingerprint: Sha256::digest(der)
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<_>>()
+            .join(":"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Leaf for CN=abc.supabase.co issued by a throwaway test CA, valid from
+    // 2024-03-01 12:00 (UTCTime) to 2055-03-01 12:00 (GeneralizedTime)
+    const LEAF: &[u8] = include_bytes!("testdata/leaf.der");
+
+    #[test]
+    fn parses_subject_issuer_and_validity() {
+        let info = parse_certificate(LEAF).unwrap();
+        assert_eq!(info.subject, "C=US, ST=California, O=Example Co, CN=abc.supabase.co");
+        assert_eq!(info.issuer, "C=US, O=Test Roots, CN=Test Root CA");
+        assert_eq!(info.not_before, "2024-03-01T12:00:00Z");
+        assert_eq!(info.not_after, "2055-03-01T12:00:00Z");
+        assert!(info.expires_in_days > 0);
+        assert!(info.sha256_fingerprint.starts_with("B2:91:89:87:3E:0B"));
+        assert_eq!(info.sha256_fingerprint.len(), 32 * 3 - 1);
+    }
+
+    #[test]
+    fn utc_time_years_follow_rfc_5280() {
+        let year = |text: &str| parse_time(UTC_TIME, text.as_bytes()).map(|t| t.format("%Y").to_string());
+        assert_eq!(year("491231235959Z").as_deref(), Some("2049"));
+        assert_eq!(year("500101000000Z").as_deref(), Some("1950"));
+        assert_eq!(year("5001010000Z").as_deref(), Some("1950"), "seconds are optional");
+        assert_eq!(year("500101000000"), None, "local times are rejected");
+        assert_eq!(year("501301000000Z"), None);
+    }
+
+    #[test]
+    fn decodes_bmp_strings() {
+        assert_eq!(decode_string(BMP_STRING, &[0x00, 0x41, 0x00, 0xe9]), "Aé");
+        assert_eq!(decode_string(0x0c, "Zürich".as_bytes()), "Zürich");
+    }
+
+    #[test]
+    fn truncated_input_is_rejected() {
+        for len in 0..LEAF.len() {
+            assert!(parse_certificate(&LEAF[..len]).is_none(), "prefix of {} bytes", len);
+        }
+    }
+
+    #[test]
+    fn oversized_lengths_are_rejected() {
+        // Outer SEQUENCE claiming 4 GiB, then a length of more than four bytes
+        let mut huge = vec![SEQUENCE, 0x84, 0xff, 0xff, 0xff, 0xff];
+        huge.extend_from_slice(&LEAF[4..]);
+        assert!(parse_certificate(&huge).is_none());
+        let mut too_long = vec![SEQUENCE, 0x85, 0x00, 0x00, 0x00, 0x01, 0xbd];
+        too_long.extend_from_slice(&LEAF[4..]);
+        assert!(parse_certificate(&too_long).is_none());
+
+        // TBSCertificate claiming one byte more than the certificate holds
+        let mut inner = LEAF.to_vec();
+        assert_eq!(&inner[4..8], &[SEQUENCE, 0x82, 0x01, 0x64]);
+        inner[7] = 0xff;
+        assert!(parse_certificate(&inner).is_none());
+    }
+
+    #[test]
+    fn garbage_is_rejected() {
+        assert!(parse_certificate(&[]).is_none());
+        assert!(parse_certificate(&[SEQUENCE]).is_none());
+        assert!(parse_certificate(&[SEQUENCE, 0x80]).is_none());
+        assert!(parse_certificate(b"-----BEGIN CERTIFICATE-----").is_none());
+        assert!(parse_certificate(&[SEQUENCE, 0x02, SEQUENCE, 0x00]).is_none());
+    }
+}
@@ -3,6 +3,7 @@ import { BrowserRouter as Router, Routes, Route } from 'react-router-dom';
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
 import { AssessmentProvider } from './context/AssessmentContext';
+import { BackendStatusProvider } from './context/BackendStatusContext';
 import { Layout } from './components/Layout';
 import { Home } from './pages/Home';
 import { NewAssessment } from './pages/NewAssessment';
@@ -10,6 +11,16 @@ import { Review } from './pages/Review';
 import { Settings } from './pages/Settings';
 import { SetupWizard } from './components/SetupWizard';
 
+// Mirrors StartupState on the Rust side
+interface StartupState {
+  phase: 'idle' | 'preparing' | 'spawning' | 'polling' | 'warming_up' | 'ready' | 'failed';
+  port: number | null;
+  attempt: number;
+  max_attempts: number;
+  elapsed_ms: number;
+  last_failure_reason: string | null;
+}
+
 // Check if running in Tauri
 const isTauri = () => {
   return window.__TAURI__ !== undefined;
@@ -20,6 +31,10 @@ function App() {
   const [isLoading, setIsLoading] = useState(true);
   const [backendPort, setBackendPort] = useState<number | null>(null);
   const [backendStatus, setBackendStatus] = useState<string>('initializing');
+  const [isShuttingDown, setIsShuttingDown] = useState(false);
+  const [dataDirError, setDataDirError] = useState<string | null>(null);
+  const [logWriteError, setLogWriteError] = useState<string | null>(null);
+  const [startup, setStartup] = useState<StartupState | null>(null);
 
   useEffect(() => {
     const initializeApp = async () => {
@@ -45,6 +60,44 @@ function App() {
           setBackendStatus('terminated');
         });
         
+        const unlistenShuttingDown = await listen('shutting-down', () => {
+          setIsShuttingDown(true);
+        });
+        
+        // Restarts and backend mode switches can land on a different port; follow it
+        const unlistenReady = await listen<{ port: number }>('backend-ready', async (event) => {
+          const { setApiBase } = await import('./utils/api');
+          setApiBase(`http://127.0.0.1:${event.payload.port}`);
+          setBackendPort(event.payload.port);
+          setBackendStatus('running');
+        });
+        
+        // Sent when a (re)start finds no config, e.g. the .env was deleted while the app ran
+        const unlistenEnvMissing = await listen<{ message: string; path?: string }>('backend-env-missing', (event) => {
+          console.warn('Backend config missing:', event.payload.path ?? event.payload.message);
+          setBackendStatus('error');
+          setIsSetupComplete(false);
+        });
+        
+        // The shell re-checks (and if needed restarts) the backend itself after a wake
+        const unlistenResumed = await listen('resumed-from-sleep', (event: any) => {
+          console.warn('Resumed from sleep:', event.payload);
+        });
+        
+        // Sent once when the backend log can't be written, most often because the disk is full
+        const unlistenLogWriteFailed = await listen('log-write-failed', (event: any) => {
+          setLogWriteError(event.payload?.message ?? 'Failed to write backend log');
+        });
+        
+        // A read-only data dir would break the first save, so stop here with the path
+        try {
+          await invoke('check_data_dir_writable');
+        } catch (e: any) {
+          console.error('App data directory is not writable:', e);
+          setDataDirError(e?.message ?? String(e));
+          return;
+        }
+        
         // Check if environment is configured
         console.log('Checking environment configuration...');
         const hasConfig = await invoke<boolean>('check_env_config');
@@ -54,31 +107,41 @@ function App() {
           setBackendStatus('starting');
           console.log('Starting backend...');
           
-          try {
-            // Start backend
-            const port = await invoke<number>('start_backend');
-            console.log('Backend started on port:', port);
-            setBackendPort(port);
-            setBackendStatus('running');
-            
-            // Update API base URL
-            const backendUrl = `http://127.0.0.1:${port}`;
-            
-            // Import and update API base
-            const { setApiBase } = await import('./utils/api');
-            setApiBase(backendUrl);
-            
-            // Verify backend is accessible
-            const response = await fetch(`${backendUrl}/health`);
-            if (response.ok) {
-              console.log('Backend health check passed');
-            } else {
-              console.error('Backend health check failed');
+          const startBackend = async () => {
+            try {
+              // Start backend
+              const port = await invoke<number>('start_backend');
+              console.log('Backend started on port:', port);
+              setBackendPort(port);
+              
+              // Update API base URL
+              const backendUrl = `http://127.0.0.1:${port}`;
+              
+              // Import and update API base
+              const { setApiBase } = await import('./utils/api');
+              setApiBase(backendUrl);
+              setBackendStatus('running');
+              
+              // Verify backend is accessible
+              const response = await fetch(`${backendUrl}/health`);
+              if (response.ok) {
+                console.log('Backend health check passed');
+              } else {
+                console.error('Backend health check failed');
+              }
+            } catch (e) {
+              console.error('Failed to start backend:', e);
+              console.error('Error details:', JSON.stringify(e));
+              setBackendStatus('error');
             }
-          } catch (e) {
-            console.error('Failed to start backend:', e);
-            console.error('Error details:', JSON.stringify(e));
-            setBackendStatus('error');
+          };
+          
+          // With background start, show the app right away and let the backend catch up
+          const inBackground = await invoke<boolean>('get_background_start').catch(() => false);
+          if (inBackground) {
+            startBackend();
+          } else {
+            await startBackend();
           }
           
           setIsSetupComplete(true);
@@ -92,6 +155,11 @@ function App() {
           unlistenOutput();
           unlistenError();
           unlistenTerminated();
+          unlistenShuttingDown();
+          unlistenLogWriteFailed();
+          unlistenResumed();
+          unlistenReady();
+          unlistenEnvMissing();
         };
       } catch (e) {
         console.error('Failed to initialize app:', e);
@@ -104,6 +172,72 @@ function App() {
     initializeApp();
   }, []);
 
+  // start_backend only resolves at the end, so poll its progress for the loading screen
+  useEffect(() => {
+    if (!isTauri() || !isLoading || backendStatus !== 'starting') return;
+    const timer = setInterval(() => {
+      invoke<StartupState>('get_startup_state').then(setStartup).catch(() => {});
+    }, 500);
+    return () => clearInterval(timer);
+  }, [isLoading, backendStatus]);
+
+  // Coming back to the window is a cheap moment to confirm the backend didn't die meanwhile
+  useEffect(() => {
+    if (!isTauri()) return;
+    const onVisible = () => {
+      if (document.visibilityState === 'visible') {
+        invoke('reconcile_backend_state').catch(e => console.warn('Failed to reconcile backend state:', e));
+      }
+    };
+    document.addEventListener('visibilitychange', onVisible);
+    return () => document.removeEventListener('visibilitychange', onVisible);
+  }, []);
+
+  // Input keeps the idle auto-restart from firing while someone is using the app
+  useEffect(() => {
+    if (!isTauri()) return;
+    let last = 0;
+    const onInput = () => {
+      const now = Date.now();
+      if (now - last < 60_000) return;
+      last = now;
+      invoke('record_user_activity').catch(() => {});
+    };
+    window.addEventListener('pointerdown', onInput);
+    window.addEventListener('keydown', onInput);
+    return () => {
+      window.removeEventListener('pointerdown', onInput);
+      window.removeEventListener('keydown', onInput);
+    };
+  }, []);
+
+  // Backend cleanup on quit can take a few seconds; show that we're closing
+  if (isShuttingDown) {
+    return (
+      <div className="min-h-screen bg-gradient-to-br from-blue-50 to-indigo-100 flex items-center justify-center">
+        <div className="text-center">
+          <div className="animate-spin rounded-full h-16 w-16 border-b-4 border-blue-600 mx-auto"></div>
+          <p className="mt-4 text-gray-600">Closing...</p>
+          <p className="mt-1 text-xs text-gray-400">Stopping the grading backend</p>
+        </div>
+      </div>
+    );
+  }
+
+  if (dataDirError) {
+    return (
+      <div className="min-h-screen bg-gradient-to-br from-blue-50 to-indigo-100 flex items-center justify-center">
+        <div className="max-w-lg bg-white rounded-lg border border-red-200 p-6 text-center">
+          <p className="text-lg font-semibold text-red-700">Can't write to the app data folder</p>
+          <p className="mt-2 text-sm text-gray-600 break-all">{dataDirError}</p>
+          <p className="mt-4 text-sm text-gray-500">
+            Check the folder's permissions, or make sure it isn't on a read-only or disconnected drive, then restart the app.
+          </p>
+        </div>
+      </div>
+    );
+  }
+
   // Show loading state
   if (isLoading) {
     return (
@@ -115,6 +249,12 @@ function App() {
             <p className="mt-2 text-sm text-gray-500">Backend running on port {backendPort}</p>
           )}
           <p className="mt-1 text-xs text-gray-400">Status: {backendStatus}</p>
+          {startup?.phase === 'polling' && (
+            <p className="mt-1 text-xs text-gray-400">
+              Health check {startup.attempt}/{startup.max_attempts} ({Math.round(startup.elapsed_ms / 1000)}s)
+              {startup.last_failure_reason && ` · ${startup.last_failure_reason}`}
+            </p>
+          )}
         </div>
       </div>
     );
@@ -127,9 +267,26 @@ function App() {
 
   // Normal app
   return (
+    <BackendStatusProvider status={backendStatus}>
     <AssessmentProvider>
       <Router>
         <Layout>
+          {backendStatus === 'starting' && (
+            <div className="mb-4 rounded-lg border border-blue-200 bg-blue-50 p-3 text-sm text-blue-800 flex items-center gap-3">
+              <div className="animate-spin rounded-full h-4 w-4 border-b-2 border-blue-600"></div>
+              <span>The grading backend is starting. Grading will be available once it's ready.</span>
+            </div>
+          )}
+          {logWriteError && (
+            <div className="mb-4 rounded-lg border border-amber-200 bg-amber-50 p-3 text-sm text-amber-800 flex items-start justify-between gap-4">
+              <span>
+                {logWriteError} Backend logs are no longer being saved; free up some disk space and restart the backend.
+              </span>
+              <button onClick={() => setLogWriteError(null)} className="text-amber-600 hover:text-amber-800">
+                Dismiss
+              </button>
+            </div>
+          )}
           <Routes>
             <Route path="/" element={<Home />} />
             <Route path="/new-assessment" element={<NewAssessment />} />
@@ -139,6 +296,7 @@ function App() {
         </Layout>
       </Router>
     </AssessmentProvider>
+    </BackendStatusProvider>
   );
 }
 
@@ -0,0 +1,111 @@
+import React, { useEffect, useRef, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { Search } from 'lucide-react';
+
+// Mirrors MenuCommand on the Rust side
+interface MenuCommand {
+  id: string;
+  menu: string;
+  label: string;
+  accelerator: string | null;
+  checkable: boolean;
+  confirm: string | null;
+}
+
+// Ctrl/Cmd+K list of the app menu's commands, run through execute_menu_command
+export const CommandPalette: React.FC = () => {
+  const [open, setOpen] = useState(false);
+  const [commands, setCommands] = useState<MenuCommand[]>([]);
+  const [query, setQuery] = useState('');
+  const [selected, setSelected] = useState(0);
+  const [error, setError] = useState<string | null>(null);
+  const inputRef = useRef<HTMLInputElement>(null);
+
+  useEffect(() => {
+    const onKeyDown = (e: KeyboardEvent) => {
+      if ((e.ctrlKey || e.metaKey) && e.key.toLowerCase() === 'k') {
+        e.preventDefault();
+        setOpen(prev => !prev);
+      } else if (e.key === 'Escape') {
+        setOpen(false);
+      }
+    };
+    window.addEventListener('keydown', onKeyDown);
+    return () => window.removeEventListener('keydown', onKeyDown);
+  }, []);
+
+  useEffect(() => {
+    if (!open) return;
+    setQuery('');
+    setSelected(0);
+    setError(null);
+    invoke<MenuCommand[]>('get_menu_commands').then(setCommands).catch(e => setError(String(e)));
+    inputRef.current?.focus();
+  }, [open]);
+
+  if (!open) return null;
+
+  const needle = query.trim().toLowerCase();
+  const matches = commands.filter(c => `${c.menu} ${c.label}`.toLowerCase().includes(needle));
+
+  const run = async (command: MenuCommand | undefined) => {
+    if (!command) return;
+    try {
+      await invoke('execute_menu_command', { id: command.id });
+      setOpen(false);
+    } catch (e: any) {
+      setError(e?.message ?? String(e));
+    }
+  };
+
+  const onKeyDown = (e: React.KeyboardEvent) => {
+    if (e.key === 'ArrowDown') {
+      e.preventDefault();
+      setSelected(i => Math.min(i + 1, matches.length - 1));
+    } else if (e.key === 'ArrowUp') {
+      e.preventDefault();
+      setSelected(i => Math.max(i - 1, 0));
+    } else if (e.key === 'Enter') {
+      e.preventDefault();
+      run(matches[selected]);
+    }
+  };
+
+  return (
+    <div className="fixed inset-0 z-[100] bg-black/30 flex items-start justify-center pt-24" onClick={() => setOpen(false)}>
+      <div className="w-full max-w-lg bg-white rounded-xl shadow-2xl border border-slate-200" onClick={e => e.stopPropagation()}>
+        <div className="flex items-center gap-2 px-4 py-3 border-b border-slate-200">
+          <Search className="w-4 h-4 text-slate-400" />
+          <input
+            ref={inputRef}
+            value={query}
+            onChange={e => { setQuery(e.target.value); setSelected(0); }}
+            onKeyDown={onKeyDown}
+            placeholder="Type a command..."
+            className="flex-1 outline-none text-sm"
+          />
+        </div>
+        <ul className="max-h-80 overflow-y-auto py-1">
+          {matches.map((command, i) => (
+            <li
+              key={command.id}
+              onMouseEnter={() => setSelected(i)}
+              onClick={() => run(command)}
+              className={`px-4 py-2 text-sm flex justify-between cursor-pointer ${i === selected ? 'bg-blue-50 text-blue-800' : 'text-slate-700'}`}
+            >
+              <span>
+                <span className="text-slate-400">{command.menu}: </span>
+                {command.label}
+              </span>
+              {command.accelerator && (
+                <span className="text-xs text-slate-400 font-mono">{command.accelerator.replace('CmdOrCtrl', 'Ctrl/Cmd')}</span>
+              )}
+            </li>
+          ))}
+          {matches.length === 0 && <li className="px-4 py-2 text-sm text-slate-500">No matching commands</li>}
+        </ul>
+        {error && <p className="px-4 py-2 text-xs text-red-600 border-t border-slate-200">{error}</p>}
+      </div>
+    </div>
+  );
+};
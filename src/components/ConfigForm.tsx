@@ -0,0 +1,143 @@
+import React, { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { Save } from 'lucide-react';
+
+// Mirrors schema::ConfigField on the Rust side
+interface ConfigField {
+  key: string;
+  type: 'text' | 'url' | 'bool' | 'integer' | 'path' | 'regex';
+  min?: number;
+  max?: number;
+  default: string | null;
+  secret: boolean;
+  required: boolean;
+  description: string;
+}
+
+interface ConfigValue {
+  key: string;
+  value: string | null;
+  source: 'file' | 'system_env' | 'default' | 'unset';
+}
+
+interface ConfigFormProps {
+  // Keys the caller already edits elsewhere
+  exclude: string[];
+  onSaved: (message: string) => void;
+  onError: (message: string) => void;
+}
+
+// Typed form for every setting in the Rust config schema
+export const ConfigForm: React.FC<ConfigFormProps> = ({ exclude, onSaved, onError }) => {
+  const [schema, setSchema] = useState<ConfigField[]>([]);
+  const [values, setValues] = useState<Record<string, string>>({});
+  const [sources, setSources] = useState<Record<string, ConfigValue['source']>>({});
+  const [unknown, setUnknown] = useState<ConfigValue[]>([]);
+  const [saving, setSaving] = useState(false);
+  // Effective (file/env/default) concurrency vs. what the running backend uses
+  const [configuredConcurrency, setConfiguredConcurrency] = useState<number | null>(null);
+  const [activeConcurrency, setActiveConcurrency] = useState<number | null>(null);
+
+  const load = async () => {
+    const [fields, config] = await Promise.all([
+      invoke<ConfigField[]>('get_config_schema'),
+      invoke<{ fields: ConfigValue[]; unknown: ConfigValue[] }>('get_all_config'),
+    ]);
+    setSchema(fields.filter(f => !exclude.includes(f.key)));
+    // Defaults aren't in the file, so show them as placeholders rather than values
+    setValues(Object.fromEntries(config.fields.map(v => [v.key, v.source === 'file' ? v.value ?? '' : ''])));
+    setSources(Object.fromEntries(config.fields.map(v => [v.key, v.source])));
+    setUnknown(config.unknown);
+    const concurrency = config.fields.find(v => v.key === 'GRADING_MAX_CONCURRENCY')?.value;
+    setConfiguredConcurrency(concurrency ? Number(concurrency) : null);
+    invoke<number>('get_active_concurrency').then(setActiveConcurrency).catch(() => setActiveConcurrency(null));
+  };
+
+  useEffect(() => {
+    load().catch(e => onError(`Failed to load settings: ${e?.message ?? e}`));
+  }, []);
+
+  const save = async () => {
+    setSaving(true);
+    try {
+      const changed = Object.fromEntries(schema.map(f => [f.key, values[f.key] ?? '']));
+      const warnings = await invoke<{ message: string }[]>('set_all_config', { values: changed });
+      await load();
+      onSaved(
+        warnings.length
+          ? `Settings saved, but please double-check: ${warnings.map(w => w.message).join(' · ')}`
+          : 'Settings saved. Restart the backend to apply changes.'
+      );
+    } catch (e: any) {
+      onError(`Failed to save settings: ${e?.message ?? e}`);
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const input = (field: ConfigField) => {
+    const value = values[field.key] ?? '';
+    const set = (v: string) => setValues(prev => ({ ...prev, [field.key]: v }));
+    const placeholder = sources[field.key] === 'system_env' ? '(set by the system environment)' : field.default ?? '';
+    if (field.type === 'bool') {
+      return (
+        <select value={value} onChange={e => set(e.target.value)} className="px-2 py-1 border border-gray-300 rounded-md bg-white">
+          <option value="">{field.default ? `Default (${field.default === '1' ? 'on' : 'off'})` : 'Default'}</option>
+          <option value="1">On</option>
+          <option value="0">Off</option>
+        </select>
+      );
+    }
+    return (
+      <input
+        type={field.secret ? 'password' : field.type === 'integer' ? 'number' : 'text'}
+        min={field.min}
+        max={field.max}
+        value={value}
+        placeholder={placeholder}
+        onChange={e => set(e.target.value)}
+        className="w-full px-3 py-1.5 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+      />
+    );
+  };
+
+  return (
+    <div className="space-y-3">
+      {schema.map(field => (
+        <div key={field.key}>
+          <label className="block text-sm font-medium text-gray-700">
+            {field.key}
+            {field.required && <span className="text-red-500"> *</span>}
+          </label>
+          <p className="text-xs text-gray-500 mb-1">{field.description}</p>
+          {input(field)}
+          {field.key === 'GRADING_MAX_CONCURRENCY' && activeConcurrency !== null && configuredConcurrency !== null
+            && activeConcurrency !== configuredConcurrency && (
+            <p className="text-xs text-amber-700 mt-1">
+              The running backend is using {activeConcurrency}; restart it to apply {configuredConcurrency}.
+            </p>
+          )}
+        </div>
+      ))}
+
+      {unknown.length > 0 && (
+        <div>
+          <p className="text-sm font-medium text-gray-700">Other settings in the file</p>
+          <p className="text-xs text-gray-500 mb-1">Not managed here; edit them in the raw editor. They are kept when saving.</p>
+          {unknown.map(v => (
+            <div key={v.key} className="text-sm font-mono text-gray-600">{v.key}={v.value}</div>
+          ))}
+        </div>
+      )}
+
+      <button
+        onClick={save}
+        disabled={saving}
+        className="px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed flex items-center gap-2"
+      >
+        <Save className="w-4 h-4" />
+        {saving ? 'Saving...' : 'Save Advanced Settings'}
+      </button>
+    </div>
+  );
+};
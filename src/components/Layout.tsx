@@ -1,6 +1,17 @@
-import React from 'react';
+import React, { useEffect, useState } from 'react';
 import { Link, useLocation } from 'react-router-dom';
 import { GraduationCap, Plus } from 'lucide-react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { CommandPalette } from './CommandPalette';
+
+// Parsed from backend output by the shell (`grading-status` events)
+interface GradingStatus {
+  current: number;
+  total: number;
+  rate_per_min: number | null;
+  eta_secs: number | null;
+}
 
 interface LayoutProps {
   children: React.ReactNode;
@@ -10,6 +21,15 @@ export const Layout: React.FC<LayoutProps> = ({ children }) => {
   const location = useLocation();
   const isReview = location.pathname.includes('/review');
   const isNewAssessment = location.pathname.includes('/new-assessment');
+  const [grading, setGrading] = useState<GradingStatus | null>(null);
+
+  useEffect(() => {
+    invoke<GradingStatus | null>('get_grading_status').then(setGrading).catch(() => {});
+    const unlisten = listen<GradingStatus>('grading-status', (event) => setGrading(event.payload));
+    return () => {
+      unlisten.then(fn => fn());
+    };
+  }, []);
 
   return (
     <div className="min-h-screen bg-gradient-to-br from-slate-50 via-blue-50/30 to-indigo-50/40">
@@ -59,10 +79,26 @@ export const Layout: React.FC<LayoutProps> = ({ children }) => {
         </div>
       </header>
 
+      {/* Grading progress */}
+      {grading && grading.current < grading.total && (
+        <div className="bg-blue-50 border-b border-blue-100 text-sm text-blue-800">
+          <div className="max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-1 flex items-center gap-3">
+            <span>Grading {grading.current}/{grading.total}</span>
+            <div className="flex-1 h-1.5 bg-blue-100 rounded">
+              <div className="h-1.5 bg-blue-500 rounded" style={{ width: `${(grading.current / grading.total) * 100}%` }} />
+            </div>
+            {grading.rate_per_min !== null && <span>{grading.rate_per_min.toFixed(1)}/min</span>}
+            {grading.eta_secs !== null && <span>~{Math.ceil(grading.eta_secs / 60)} min left</span>}
+          </div>
+        </div>
+      )}
+
       {/* Main Content */}
       <main className={(isReview || isNewAssessment) ? "w-full px-4 sm:px-6 lg:px-8 py-8" : "max-w-7xl mx-auto px-4 sm:px-6 lg:px-8 py-8"}>
         {children}
       </main>
+
+      <CommandPalette />
     </div>
   );
 };
\ No newline at end of file
@@ -29,12 +29,19 @@ export function SetupWizard({ onComplete }: SetupWizardProps) {
       }
 
       // Save configuration
-      await invoke('save_env_config', {
+      const args = {
         apiKey: config.api_key,
         supabaseUrl: config.supabase_url,
         supabaseKey: config.supabase_key,
         storageBucket: config.storage_bucket || 'grading-images'
-      });
+      };
+      const result = await invoke<{ status: string; path?: string }>('save_env_config', args);
+      if (result.status === 'would_overwrite') {
+        if (!window.confirm(`A configuration already exists at ${result.path}. Overwrite it?`)) {
+          return;
+        }
+        await invoke('save_env_config', { ...args, confirm: true });
+      }
 
       // Start backend
       const port = await invoke<number>('start_backend');
@@ -1,4 +1,5 @@
 import React, { createContext, useContext, useState, useCallback, useEffect } from 'react';
+import { invoke } from '@tauri-apps/api/core';
 import { Assessment, AssessmentResults, ReasoningConfig, ModelPair } from '../types';
 import {
   createSession,
@@ -507,6 +508,15 @@ export const AssessmentProvider: React.FC<{ children: React.ReactNode }> = ({ ch
     }
   }, [loading, assessments, loadAssessmentResults, autoInferenceTriggered]);
 
+  // Mirror "any run in progress" into Rust so the native quit guard matches the UI
+  const gradingActive = assessments.some(a => a.status === 'running');
+  useEffect(() => {
+    if (!window.__TAURI__) return;
+    invoke('set_grading_active', { active: gradingActive }).catch(e =>
+      console.warn('[AssessmentContext] Failed to update grading flag:', e)
+    );
+  }, [gradingActive]);
+
   const addAssessment = useCallback(async (assessmentData: Omit<Assessment, 'id' | 'date'>) => {
     // 1) Create backend session
     const session = await createSession(
@@ -0,0 +1,18 @@
+import React, { createContext, useContext } from 'react';
+
+// Lifecycle of the bundled backend as tracked by App: 'initializing' | 'starting' | 'running' | 'error' | 'terminated'
+interface BackendStatusContextType {
+  status: string;
+  ready: boolean;
+}
+
+const BackendStatusContext = createContext<BackendStatusContextType>({ status: 'running', ready: true });
+
+export const BackendStatusProvider: React.FC<{ status: string; children: React.ReactNode }> = ({ status, children }) => (
+  // Only a start in progress blocks grading; after a failure the user may still retry
+  <BackendStatusContext.Provider value={{ status, ready: status !== 'starting' }}>
+    {children}
+  </BackendStatusContext.Provider>
+);
+
+export const useBackendStatus = () => useContext(BackendStatusContext);
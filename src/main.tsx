@@ -1,10 +1,14 @@
 import { StrictMode } from 'react';
 import { createRoot } from 'react-dom/client';
 import App from './App.tsx';
+import { LogOverlay } from './pages/LogOverlay';
 import './index.css';
 
+// The log overlay window only shows the log tail, without the app's startup flow
+const isLogOverlay = window.location.pathname === '/log-overlay';
+
 createRoot(document.getElementById('root')!).render(
   <StrictMode>
-    <App />
+    {isLogOverlay ? <LogOverlay /> : <App />}
   </StrictMode>
 );
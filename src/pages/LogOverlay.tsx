@@ -0,0 +1,60 @@
+import React, { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+import { X } from 'lucide-react';
+
+// Mirrors logs::LogLine on the Rust side
+interface LogLine {
+  seq: number;
+  stream: 'stdout' | 'stderr';
+  line: string;
+  timestamp_ms: number;
+}
+
+interface OverlayLine {
+  stream: LogLine['stream'];
+  line: string;
+}
+
+const OVERLAY_LINES = 8;
+
+// Contents of the frameless always-on-top `log-overlay` window
+export const LogOverlay: React.FC = () => {
+  const [lines, setLines] = useState<OverlayLine[]>([]);
+
+  useEffect(() => {
+    const append = (stream: OverlayLine['stream']) => (event: { payload: string }) =>
+      setLines(prev => [...prev, { stream, line: event.payload }].slice(-OVERLAY_LINES));
+
+    invoke<LogLine[]>('get_recent_logs', { limit: OVERLAY_LINES })
+      .then(recent => setLines(recent.map(l => ({ stream: l.stream, line: l.line }))))
+      .catch(() => {});
+    const unlistenOutput = listen<string>('backend-output', append('stdout'));
+    const unlistenError = listen<string>('backend-error', append('stderr'));
+    return () => {
+      unlistenOutput.then(fn => fn());
+      unlistenError.then(fn => fn());
+    };
+  }, []);
+
+  return (
+    <div className="h-screen flex flex-col bg-slate-900/90 text-slate-100 text-xs font-mono select-none">
+      <div data-tauri-drag-region className="flex items-center justify-between px-2 py-1 bg-slate-800 cursor-move">
+        <span data-tauri-drag-region className="text-slate-400">Backend log</span>
+        <button
+          onClick={() => invoke('toggle_log_overlay')}
+          className="text-slate-400 hover:text-white"
+          title="Close overlay"
+        >
+          <X className="w-3.5 h-3.5" />
+        </button>
+      </div>
+      <div className="flex-1 overflow-hidden px-2 py-1 flex flex-col justify-end">
+        {lines.length === 0 && <p className="text-slate-500">Waiting for backend output...</p>}
+        {lines.map((l, i) => (
+          <p key={i} className={`truncate ${l.stream === 'stderr' ? 'text-amber-300' : ''}`}>{l.line}</p>
+        ))}
+      </div>
+    </div>
+  );
+};
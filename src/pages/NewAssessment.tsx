@@ -2,6 +2,7 @@ import React, { useEffect, useMemo, useRef, useState } from 'react';
 import { useNavigate } from 'react-router-dom';
 import { ArrowLeft, AlertCircle, Upload, Sparkles, ArrowRight, Link2 } from 'lucide-react';
 import { useAssessments } from '../context/AssessmentContext';
+import { useBackendStatus } from '../context/BackendStatusContext';
 import { FileUpload } from '../components/FileUploadHTML5';
 import { MultiSelect } from '../components/MultiSelect';
 import { NumberInput } from '../components/NumberInput';
@@ -9,6 +10,34 @@ import { useOpenRouterModels } from '../hooks/useOpenRouterModels';
 import { ReasoningLevel } from '../types';
 import { getTemplates, Template } from '../utils/api';
 
+interface UsageInfo {
+  usage: number;
+  limit: number | null;
+  limit_remaining: number | null;
+  remaining_credits: number | null;
+  low_credit: boolean;
+}
+
+// Ask before starting a run when OpenRouter credit is nearly gone. If usage can't be
+// checked (web mode, endpoint down, no key) the run just proceeds.
+const confirmIfCreditLow = async (): Promise<boolean> => {
+  if (!window.__TAURI__) return true;
+  try {
+    const { invoke } = await import('@tauri-apps/api/core');
+    const config = await invoke<{ api_key: string }>('get_env_config');
+    if (!config.api_key) return true;
+    const usage = await invoke<UsageInfo>('get_openrouter_usage', { apiKey: config.api_key });
+    if (!usage.low_credit) return true;
+    const remaining = usage.limit_remaining ?? usage.remaining_credits ?? 0;
+    return window.confirm(
+      `OpenRouter credit is low ($${remaining.toFixed(2)} remaining). The run may stop partway through. Start anyway?`
+    );
+  } catch (error) {
+    console.warn('[NewAssessment] Could not check OpenRouter usage:', error);
+    return true;
+  }
+};
+
 export const NewAssessment: React.FC = () => {
   const navigate = useNavigate();
   const { addAssessment, loadTemplateData } = useAssessments();
@@ -35,6 +64,7 @@ export const NewAssessment: React.FC = () => {
 
   const [errors, setErrors] = useState<Record<string, string>>({});
   const [isSubmitting, setIsSubmitting] = useState(false);
+  const { ready: backendReady } = useBackendStatus();
   const [isLoadingTemplate, setIsLoadingTemplate] = useState(false);
 
   const [rubricReasoningBySelection, setRubricReasoningBySelection] = useState<Array<{ level: ReasoningLevel; tokens?: number }>>([]);
@@ -276,6 +306,11 @@ export const NewAssessment: React.FC = () => {
 
     setIsSubmitting(true);
 
+    if (!(await confirmIfCreditLow())) {
+      setIsSubmitting(false);
+      return;
+    }
+
     try {
       console.log('[NewAssessment] Submitting assessment with model pairs:', {
         assessmentName: formData.name,
@@ -778,7 +813,8 @@ export const NewAssessment: React.FC = () => {
           <div className="mt-10 flex justify-center">
             <button
               type="submit"
-              disabled={isSubmitting}
+              disabled={isSubmitting || !backendReady}
+              title={backendReady ? undefined : 'Waiting for the grading backend to start'}
               className="px-8 py-4 bg-gradient-to-r from-blue-600 to-indigo-600 text-white font-semibold rounded-xl hover:from-blue-700 hover:to-indigo-700 focus:outline-none focus:ring-2 focus:ring-blue-500 focus:ring-offset-2 disabled:opacity-50 disabled:cursor-not-allowed transition-all duration-200 shadow-lg hover:shadow-xl transform hover:-translate-y-1 min-w-48"
             >
               {isSubmitting ? (
@@ -1,10 +1,17 @@
 import React, { useEffect, useState, useRef } from 'react';
 import { useParams, useNavigate, Link } from 'react-router-dom';
-import { ArrowLeft, ArrowRight, BarChart3, MessageCircle, Trophy, Target, Brain, Info, Download } from 'lucide-react';
+import { ArrowLeft, ArrowRight, BarChart3, MessageCircle, Trophy, Target, Brain, Info, Download, FileSpreadsheet, Printer, History } from 'lucide-react';
 import { useAssessments } from '../context/AssessmentContext';
 import { getRubricResults } from '../utils/api';  // NEW: Import rubric results API
 import { save } from '@tauri-apps/plugin-dialog';
 import { writeTextFile } from '@tauri-apps/plugin-fs';
+import { invoke } from '@tauri-apps/api/core';
+
+interface RecentExport {
+  path: string;
+  exported_ms: number;
+  job_id: string | null;
+}
 
 export const Review: React.FC = () => {
   const { id } = useParams<{ id: string }>();
@@ -17,6 +24,7 @@ export const Review: React.FC = () => {
   const [rubricResults, setRubricResults] = useState<any>(null);  // NEW: Store rubric results
   const [tokenUsage, setTokenUsage] = useState<any>(null);  // NEW: Store token usage data
   const [hoveredAttempt, setHoveredAttempt] = useState<string | null>(null);
+  const [recentExports, setRecentExports] = useState<RecentExport[]>([]);
   const [hoveredModel, setHoveredModel] = useState<string | null>(null);
   const [tooltipPosition, setTooltipPosition] = useState<'above' | 'below'>('below');
   const tooltipRef = useRef<HTMLDivElement>(null);
@@ -395,6 +403,48 @@ export const Review: React.FC = () => {
     }
   };
 
+  // Spreadsheet export (xlsx or csv, chosen in the save dialog) is written by the Rust side
+  const handleSpreadsheetExport = async () => {
+    if (!id) return;
+    try {
+      const path = await invoke<string | null>('export_results_xlsx', { jobId: id });
+      if (path) {
+        console.log('Results exported to:', path);
+        loadRecentExports();
+      }
+    } catch (error: any) {
+      alert(`Failed to export results: ${error?.message ?? error}`);
+    }
+  };
+
+  const loadRecentExports = () => {
+    invoke<RecentExport[]>('get_recent_exports').then(setRecentExports).catch(() => {});
+  };
+
+  useEffect(() => {
+    if (window.__TAURI__ !== undefined) {
+      loadRecentExports();
+    }
+  }, []);
+
+  const openRecentExport = async (path: string, reveal: boolean) => {
+    try {
+      await invoke('open_recent_export', { path, reveal });
+    } catch (error: any) {
+      alert(`Failed to open ${path}: ${error?.message ?? error}`);
+      loadRecentExports();
+    }
+  };
+
+  const handlePrint = async () => {
+    if (!id) return;
+    try {
+      await invoke('print_results', { jobId: id });
+    } catch (error: any) {
+      alert(`Failed to print results: ${error?.message ?? error}`);
+    }
+  };
+
   // Helper: Download text file using Tauri's native dialog
   const downloadTextFile = async (content: string, filename: string) => {
     try {
@@ -667,6 +717,45 @@ export const Review: React.FC = () => {
               <span className="text-slate-600">Iterations:</span>
               <span className="font-semibold text-slate-900 ml-1">{assessment.iterations}</span>
             </div>
+            <button
+              onClick={handleSpreadsheetExport}
+              className="inline-flex items-center bg-white/80 backdrop-blur-sm px-4 py-2 rounded-lg shadow-md text-slate-700 hover:text-slate-900 hover:bg-white transition-all duration-200"
+            >
+              <FileSpreadsheet className="w-4 h-4 mr-2" />
+              Export Spreadsheet
+            </button>
+            <button
+              onClick={handlePrint}
+              className="inline-flex items-center bg-white/80 backdrop-blur-sm px-4 py-2 rounded-lg shadow-md text-slate-700 hover:text-slate-900 hover:bg-white transition-all duration-200"
+            >
+              <Printer className="w-4 h-4 mr-2" />
+              Print
+            </button>
+            {recentExports.length > 0 && (
+              <details className="relative bg-white/80 backdrop-blur-sm px-4 py-2 rounded-lg shadow-md text-slate-700">
+                <summary className="inline-flex items-center cursor-pointer hover:text-slate-900">
+                  <History className="w-4 h-4 mr-2" />
+                  Recent Exports
+                </summary>
+                <div className="absolute right-0 mt-3 w-96 bg-white rounded-lg shadow-lg border border-slate-200 p-2 z-10 space-y-1">
+                  {recentExports.map(entry => (
+                    <div key={entry.path} className="flex items-center justify-between gap-2 text-sm px-2 py-1 rounded hover:bg-slate-50">
+                      <div className="min-w-0">
+                        <div className="truncate font-medium" title={entry.path}>{entry.path.split(/[\\/]/).pop()}</div>
+                        <div className="text-xs text-slate-500">
+                          {new Date(entry.exported_ms).toLocaleString()}
+                          {entry.job_id === id && ' · this assessment'}
+                        </div>
+                      </div>
+                      <div className="flex gap-2 shrink-0">
+                        <button onClick={() => openRecentExport(entry.path, false)} className="text-blue-600 hover:underline">Open</button>
+                        <button onClick={() => openRecentExport(entry.path, true)} className="text-blue-600 hover:underline">Show</button>
+                      </div>
+                    </div>
+                  ))}
+                </div>
+              </details>
+            )}
           </div>
         </div>
       </div>
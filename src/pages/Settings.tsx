@@ -2,7 +2,8 @@ import React, { useEffect, useState } from 'react';
 import { getPromptSettings, putPromptSettings, PromptSettingsRes, getRubricPromptSettings, putRubricPromptSettings, RubricPromptSettingsRes, getTemplates, saveTemplate, deleteTemplate, Template } from '../utils/api';
 import { invoke } from '@tauri-apps/api/core';
 import { listen } from '@tauri-apps/api/event';
-import { Save, RefreshCw, FolderOpen, FileEdit, AlertCircle, FileText, Settings as SettingsIcon, Plus, Trash2 } from 'lucide-react';
+import { ConfigForm } from '../components/ConfigForm';
+import { Save, RefreshCw, FolderOpen, FileEdit, AlertCircle, FileText, Settings as SettingsIcon, Plus, Trash2, Archive, Link2, Gauge, Download, Upload, Wifi, ShieldCheck, Camera, Lock, Stethoscope, ListChecks } from 'lucide-react';
 
 interface EnvConfig {
   api_key: string;
@@ -11,6 +12,89 @@ interface EnvConfig {
   storage_bucket: string;
 }
 
+interface ConnectivityReport {
+  all_ok: boolean;
+  hosts: { service: string; host: string; status: string; detail?: string | null; latency_ms?: number | null; via_proxy?: string | null }[];
+}
+
+interface TlsInfo {
+  host: string;
+  port: number;
+  chain_valid: boolean;
+  validation_error: string | null;
+  certificate: { subject: string; issuer: string; not_after: string; expires_in_days: number; sha256_fingerprint: string } | null;
+  hint: string | null;
+  via_proxy?: string | null;
+}
+
+interface DiagnosticOutput {
+  stdout: string[];
+  stderr: string[];
+  exit_code: number | null;
+  timed_out: boolean;
+  truncated: boolean;
+  elapsed_ms: number;
+  spawn_error: string | null;
+  cli?: {
+    compatible: boolean;
+    flags: { flag: string; required: boolean; present: boolean }[];
+    error: string | null;
+  };
+}
+
+interface RestartStats {
+  since_ms: number;
+  restarts: number;
+  crashes: number;
+  last_crash_ms: number | null;
+  recent_crashes: number;
+  crash_looping: boolean;
+}
+
+interface HardwareAcceleration {
+  enabled: boolean;
+  active: boolean;
+  supported: boolean;
+}
+
+// Mirrors BackendValidationReport on the Rust side
+interface BackendValidationReport {
+  status: 'validated' | 'unsupported';
+  settings: { key: string; status: 'ok' | 'warning' | 'error'; message: string }[];
+}
+
+// Mirrors ProxyConfig on the Rust side
+interface ProxyConfig {
+  http_proxy: string | null;
+  https_proxy: string | null;
+  no_proxy: string | null;
+}
+
+interface EnvRepairReport {
+  path: string;
+  healthy: boolean;
+  issues: { line: number | null; problem: string }[];
+  action: { kind: 'none' | 'restore_available' | 'restored' | 'no_usable_backup'; from?: string };
+}
+
+interface LatencyStats {
+  samples: number;
+  failures: number;
+  min_ms: number;
+  median_ms: number;
+  p95_ms: number;
+  max_ms: number;
+}
+
+// File-writing commands fail with { kind, message, path? }; disk-full messages already say what to do
+function describeFileError(error: any): string {
+  const message = error?.message ?? String(error);
+  if (error?.kind === 'file_locked') {
+    return `${message} The file is in use by another program; close it and try again.`;
+  }
+  return message;
+}
+
 export const Settings: React.FC = () => {
   // Tab state
   const [activeTab, setActiveTab] = useState<'rubric-prompt' | 'prompt' | 'environment'>('rubric-prompt');
@@ -46,6 +130,24 @@ export const Settings: React.FC = () => {
     storage_bucket: 'grading-images'
   });
   const [envSaving, setEnvSaving] = useState(false);
+  const [testingConnections, setTestingConnections] = useState(false);
+  const [checkingNetwork, setCheckingNetwork] = useState(false);
+  const [checkingTls, setCheckingTls] = useState(false);
+  const [validatingWithBackend, setValidatingWithBackend] = useState(false);
+  const [backendValidation, setBackendValidation] = useState<BackendValidationReport | null>(null);
+  const [rawEnv, setRawEnv] = useState<string | null>(null);
+  const [rawEnvSaving, setRawEnvSaving] = useState(false);
+  const [backgroundStart, setBackgroundStart] = useState(false);
+  const [hardwareAcceleration, setHardwareAcceleration] = useState<HardwareAcceleration | null>(null);
+  const [proxy, setProxy] = useState<ProxyConfig>({ http_proxy: null, https_proxy: null, no_proxy: null });
+  const [proxySaving, setProxySaving] = useState(false);
+  const [benchmarking, setBenchmarking] = useState(false);
+  const [selfChecking, setSelfChecking] = useState(false);
+  const [restartStats, setRestartStats] = useState<RestartStats | null>(null);
+  const [diagnostic, setDiagnostic] = useState<DiagnosticOutput | null>(null);
+  const [logLevel, setLogLevel] = useState('info');
+  const [bundleScreenshots, setBundleScreenshots] = useState(false);
+  const [latency, setLatency] = useState<LatencyStats | null>(null);
   const [envLoading, setEnvLoading] = useState(true);
   const [backendStatus, setBackendStatus] = useState<'running' | 'stopped' | 'restarting'>('running');
 
@@ -60,6 +162,11 @@ export const Settings: React.FC = () => {
     loadTemplates();
     if (isTauri()) {
       loadEnvConfig();
+      invoke<boolean>('get_background_start').then(setBackgroundStart).catch(() => {});
+      invoke<string>('get_log_level').then(setLogLevel).catch(() => {});
+      invoke<RestartStats>('get_restart_stats').then(setRestartStats).catch(() => {});
+      invoke<HardwareAcceleration>('get_hardware_acceleration').then(setHardwareAcceleration).catch(() => {});
+      invoke<ProxyConfig>('get_proxy_config').then(setProxy).catch(() => {});
       
       // Listen for restart backend event
       const unlisten = listen('restart-backend', async () => {
@@ -326,16 +433,27 @@ export const Settings: React.FC = () => {
       setError(null);
       setSuccess(null);
       
-      await invoke('save_env_config', {
+      const args = {
         apiKey: envConfig.api_key,
         supabaseUrl: envConfig.supabase_url,
         supabaseKey: envConfig.supabase_key,
         storageBucket: envConfig.storage_bucket
-      });
+      };
+      type SaveResult = { status: string; path?: string; warnings?: { key: string; message: string }[] };
+      let result = await invoke<SaveResult>('save_env_config', args);
+      if (result.status === 'would_overwrite') {
+        if (!window.confirm(`A configuration already exists at ${result.path}. Overwrite it?`)) {
+          return;
+        }
+        result = await invoke<SaveResult>('save_env_config', { ...args, confirm: true });
+      }
       
       setSuccess('Environment configuration saved successfully! Restart the backend to apply changes.');
-    } catch (error) {
-      setError(`Failed to save configuration: ${error}`);
+      if (result.warnings?.length) {
+        setError(`Saved, but please double-check: ${result.warnings.map(w => w.message).join(' · ')}`);
+      }
+    } catch (error: any) {
+      setError(`Failed to save configuration: ${describeFileError(error)}`);
     } finally {
       setEnvSaving(false);
     }
@@ -352,9 +470,9 @@ export const Settings: React.FC = () => {
       
       setBackendStatus('running');
       setSuccess(`Backend restarted successfully on port ${port}`);
-    } catch (error) {
+    } catch (error: any) {
       setBackendStatus('stopped');
-      setError(`Failed to restart backend: ${error}`);
+      setError(`Failed to restart backend: ${error?.message ?? error}`);
     }
   };
 
@@ -379,6 +497,275 @@ export const Settings: React.FC = () => {
     }
   };
 
+  const testConnections = async () => {
+    setTestingConnections(true);
+    setError(null);
+    setSuccess(null);
+    const describe = (error: any) =>
+      error?.kind === 'timeout' ? `${error.message} (check your network or firewall)` : (error?.message ?? String(error));
+    const results = await Promise.allSettled([
+      invoke<{ latency_ms: number }>('test_openrouter_connection', { apiKey: envConfig.api_key }),
+      invoke<{ latency_ms: number }>('test_supabase_connection', {
+        supabaseUrl: envConfig.supabase_url,
+        supabaseKey: envConfig.supabase_key,
+      }),
+    ]);
+    const [openrouter, supabase] = results.map((r, i) => {
+      const name = i === 0 ? 'OpenRouter' : 'Supabase';
+      return r.status === 'fulfilled'
+        ? `${name}: OK (${r.value.latency_ms} ms)`
+        : `${name}: ${describe(r.reason)}`;
+    });
+    if (results.every(r => r.status === 'fulfilled')) {
+      setSuccess(`${openrouter} · ${supabase}`);
+    } else {
+      setError(`${openrouter} · ${supabase}`);
+    }
+    setTestingConnections(false);
+  };
+
+  const checkNetwork = async () => {
+    setCheckingNetwork(true);
+    setError(null);
+    setSuccess(null);
+    const labels: Record<string, string> = { ok: 'reachable', dns_fail: 'DNS lookup failed', blocked: 'blocked', timeout: 'timed out' };
+    try {
+      const report = await invoke<ConnectivityReport>('check_connectivity', {
+        supabaseUrl: envConfig.supabase_url || null
+      });
+      const summary = report.hosts
+        .map(h => `${h.service} (${h.host}${h.via_proxy ? ` via proxy ${h.via_proxy}` : ''}): ${labels[h.status] ?? h.status}${h.detail ? ` - ${h.detail}` : ''}`)
+        .join(' · ');
+      if (report.all_ok) {
+        setSuccess(summary);
+      } else {
+        setError(`${summary}. Your network or firewall may be blocking these services.`);
+      }
+    } catch (error: any) {
+      setError(`Failed to check network: ${error?.message ?? error}`);
+    } finally {
+      setCheckingNetwork(false);
+    }
+  };
+
+  const checkTls = async () => {
+    setCheckingTls(true);
+    setError(null);
+    setSuccess(null);
+    try {
+      const info = await invoke<TlsInfo>('inspect_supabase_tls', {
+        supabaseUrl: envConfig.supabase_url || null
+      });
+      const cert = info.certificate;
+      const via = info.via_proxy ? ` (via proxy ${info.via_proxy})` : '';
+      const details = cert
+        ? `Issued to ${cert.subject || '(unnamed)'} by ${cert.issuer || '(unnamed)'}, expires ${cert.not_after} (${cert.expires_in_days} days). SHA-256 ${cert.sha256_fingerprint}`
+        : 'The server sent no certificate details';
+      if (info.chain_valid) {
+        setSuccess(`Certificate for ${info.host}${via} is trusted. ${details}`);
+      } else {
+        setError(`Certificate for ${info.host}${via} is not trusted (${info.validation_error}). ${details}. ${info.hint ?? ''}`);
+      }
+    } catch (error: any) {
+      setError(`Failed to inspect certificate: ${error?.message ?? error}`);
+    } finally {
+      setCheckingTls(false);
+    }
+  };
+
+  const validateWithBackend = async () => {
+    setValidatingWithBackend(true);
+    setError(null);
+    setSuccess(null);
+    try {
+      const report = await invoke<BackendValidationReport>('validate_config_with_backend');
+      setBackendValidation(report);
+      if (report.status === 'unsupported') {
+        setError("The running backend can't validate settings; update or restart it to use this check.");
+      } else if (report.settings.every(v => v.status === 'ok')) {
+        setSuccess('The backend accepted every setting it checked.');
+      }
+    } catch (error: any) {
+      setBackendValidation(null);
+      setError(`Failed to validate with the backend: ${error?.message ?? error}`);
+    } finally {
+      setValidatingWithBackend(false);
+    }
+  };
+
+  const checkEnvFile = async () => {
+    setError(null);
+    setSuccess(null);
+    try {
+      let report = await invoke<EnvRepairReport>('validate_and_repair_env', { restore: false });
+      if (report.healthy) {
+        setSuccess('The configuration file looks fine.');
+        return;
+      }
+      const problems = report.issues.map(i => (i.line ? `line ${i.line}: ${i.problem}` : i.problem)).join('; ');
+      if (report.action.kind === 'restore_available') {
+        if (!window.confirm(`The configuration file has problems (${problems}). Restore the last backup?`)) {
+          setError(`Configuration file problems: ${problems}`);
+          return;
+        }
+        report = await invoke<EnvRepairReport>('validate_and_repair_env', { restore: true });
+      }
+      if (report.action.kind === 'restored') {
+        setSuccess('Restored the configuration from its backup. Restart the backend to apply it.');
+        await loadEnvConfig();
+      } else {
+        setError(`Configuration file problems: ${problems}. No usable backup was found; please re-enter your settings.`);
+      }
+    } catch (error) {
+      setError(`Failed to check configuration file: ${describeFileError(error)}`);
+    }
+  };
+
+  const openRawEnvEditor = async () => {
+    try {
+      setRawEnv(await invoke<string>('read_env_raw'));
+    } catch (error: any) {
+      setError(`Failed to read env file: ${error?.message ?? error}`);
+    }
+  };
+
+  const saveRawEnv = async () => {
+    if (rawEnv === null) return;
+    setRawEnvSaving(true);
+    setError(null);
+    setSuccess(null);
+    try {
+      await invoke('write_env_raw', { content: rawEnv });
+      setRawEnv(null);
+      setSuccess('Environment file saved (previous version backed up to .env.bak). Restart the backend to apply changes.');
+    } catch (error: any) {
+      setError(`Failed to save env file: ${describeFileError(error)}`);
+    } finally {
+      setRawEnvSaving(false);
+    }
+  };
+
+  const toggleBackgroundStart = async (enabled: boolean) => {
+    try {
+      await invoke('set_background_start', { enabled });
+      setBackgroundStart(enabled);
+    } catch (error) {
+      setError(`Failed to save startup preference: ${error}`);
+    }
+  };
+
+  const toggleHardwareAcceleration = async (enabled: boolean) => {
+    try {
+      const result = await invoke<HardwareAcceleration>('set_hardware_acceleration', { enabled });
+      setHardwareAcceleration(result);
+      if (result.enabled !== result.active
+        && window.confirm('Hardware acceleration changes apply after restarting the application. Restart now?')) {
+        await invoke('relaunch_app');
+      }
+    } catch (error) {
+      setError(`Failed to change hardware acceleration: ${error}`);
+    }
+  };
+
+  const saveProxy = async () => {
+    try {
+      setProxySaving(true);
+      setProxy(await invoke<ProxyConfig>('set_proxy_config', { config: proxy }));
+      setError(null);
+      setSuccess('Proxy settings saved. Restart the backend so it uses them too.');
+    } catch (error: any) {
+      setSuccess(null);
+      setError(`Failed to save proxy settings: ${error?.message ?? error}`);
+    } finally {
+      setProxySaving(false);
+    }
+  };
+
+  const changeLogLevel = async (level: string) => {
+    try {
+      setLogLevel(await invoke<string>('set_log_level', { level }));
+    } catch (error) {
+      setError(`Failed to change log level: ${error}`);
+    }
+  };
+
+  const runBenchmark = async () => {
+    try {
+      setBenchmarking(true);
+      setError(null);
+      setLatency(await invoke<LatencyStats>('benchmark_backend', { samples: 50 }));
+    } catch (error) {
+      setError(`Failed to benchmark backend: ${error}`);
+    } finally {
+      setBenchmarking(false);
+    }
+  };
+
+  const resetRestartStats = async () => {
+    try {
+      setRestartStats(await invoke<RestartStats>('reset_restart_stats'));
+    } catch (error) {
+      setError(`Failed to reset restart counters: ${error}`);
+    }
+  };
+
+  const runSelfCheck = async () => {
+    setSelfChecking(true);
+    setError(null);
+    try {
+      setDiagnostic(await invoke<DiagnosticOutput>('run_backend_diagnostic'));
+    } catch (error) {
+      setError(`Failed to run backend self-check: ${error}`);
+    } finally {
+      setSelfChecking(false);
+    }
+  };
+
+  const exportPreferences = async () => {
+    try {
+      const path = await invoke<string | null>('export_preferences');
+      if (path) {
+        setSuccess(`Preferences saved to ${path}`);
+      }
+    } catch (error) {
+      setError(`Failed to export preferences: ${describeFileError(error)}`);
+    }
+  };
+
+  const importPreferences = async () => {
+    try {
+      if (await invoke<boolean>('import_preferences')) {
+        invoke<boolean>('get_background_start').then(setBackgroundStart).catch(() => {});
+        invoke<string>('get_log_level').then(setLogLevel).catch(() => {});
+        setSuccess('Preferences imported. Some settings take effect after restarting the app.');
+      }
+    } catch (error) {
+      setError(`Failed to import preferences: ${describeFileError(error)}`);
+    }
+  };
+
+  const exportSupportBundle = async () => {
+    try {
+      const path = await invoke<string | null>('export_support_bundle', { includeScreenshots: bundleScreenshots });
+      if (path) {
+        setSuccess(`Support bundle saved to ${path}`);
+      }
+    } catch (error) {
+      setError(`Failed to export support bundle: ${describeFileError(error)}`);
+    }
+  };
+
+  const captureScreenshot = async () => {
+    try {
+      const path = await invoke<string | null>('capture_window_screenshot', { choosePath: false });
+      if (path) {
+        setSuccess(`Screenshot saved to ${path}. Tick "Include screenshots" to add it to the next support bundle.`);
+      }
+    } catch (error) {
+      setError(`Failed to take screenshot: ${describeFileError(error)}`);
+    }
+  };
+
   function onReset() {
     if (initial) {
       setSystemTemplate(initial.system_template);
@@ -733,6 +1120,42 @@ export const Settings: React.FC = () => {
                   {envSaving ? 'Saving...' : 'Save Configuration'}
                 </button>
 
+                <button
+                  onClick={testConnections}
+                  disabled={testingConnections}
+                  className="px-4 py-2 bg-gray-100 text-gray-800 rounded-md hover:bg-gray-200 disabled:opacity-50 disabled:cursor-not-allowed flex items-center gap-2"
+                >
+                  <Link2 className={`w-4 h-4 ${testingConnections ? 'animate-pulse' : ''}`} />
+                  {testingConnections ? 'Testing...' : 'Test Connections'}
+                </button>
+
+                <button
+                  onClick={checkNetwork}
+                  disabled={checkingNetwork}
+                  className="px-4 py-2 bg-gray-100 text-gray-800 rounded-md hover:bg-gray-200 disabled:opacity-50 disabled:cursor-not-allowed flex items-center gap-2"
+                >
+                  <Wifi className={`w-4 h-4 ${checkingNetwork ? 'animate-pulse' : ''}`} />
+                  {checkingNetwork ? 'Checking...' : 'Check Network'}
+                </button>
+
+                <button
+                  onClick={checkTls}
+                  disabled={checkingTls}
+                  className="px-4 py-2 bg-gray-100 text-gray-800 rounded-md hover:bg-gray-200 disabled:opacity-50 disabled:cursor-not-allowed flex items-center gap-2"
+                >
+                  <Lock className={`w-4 h-4 ${checkingTls ? 'animate-pulse' : ''}`} />
+                  {checkingTls ? 'Checking...' : 'Check Certificate'}
+                </button>
+
+                <button
+                  onClick={validateWithBackend}
+                  disabled={validatingWithBackend}
+                  className="px-4 py-2 bg-gray-100 text-gray-800 rounded-md hover:bg-gray-200 disabled:opacity-50 disabled:cursor-not-allowed flex items-center gap-2"
+                >
+                  <ListChecks className={`w-4 h-4 ${validatingWithBackend ? 'animate-pulse' : ''}`} />
+                  {validatingWithBackend ? 'Validating...' : 'Validate With Backend'}
+                </button>
+
                 <button
                   onClick={restartBackend}
                   disabled={backendStatus === 'restarting'}
@@ -742,6 +1165,64 @@ export const Settings: React.FC = () => {
                   {backendStatus === 'restarting' ? 'Restarting...' : 'Restart Backend'}
                 </button>
               </div>
+
+              {backendValidation?.status === 'validated' && backendValidation.settings.some(v => v.status !== 'ok') && (
+                <ul className="mt-4 space-y-1 text-sm">
+                  {backendValidation.settings.filter(v => v.status !== 'ok').map(v => (
+                    <li key={v.key} className={v.status === 'error' ? 'text-red-700' : 'text-amber-700'}>
+                      <span className="font-mono">{v.key}</span>: {v.message}
+                    </li>
+                  ))}
+                </ul>
+              )}
+
+              <details className="mt-6">
+                <summary className="cursor-pointer font-medium text-gray-700">Advanced Settings</summary>
+                <div className="mt-3">
+                  <ConfigForm
+                    exclude={[
+                      'OPENROUTER_API_KEY', 'SUPABASE_URL', 'SUPABASE_SERVICE_ROLE_KEY', 'SUPABASE_STORAGE_BUCKET',
+                      'HTTP_PROXY', 'HTTPS_PROXY', 'NO_PROXY',
+                    ]}
+                    onSaved={(message) => { setError(null); setSuccess(message); }}
+                    onError={(message) => { setSuccess(null); setError(message); }}
+                  />
+                </div>
+              </details>
+
+              <details className="mt-4">
+                <summary className="cursor-pointer font-medium text-gray-700">Network Proxy</summary>
+                <div className="mt-3 space-y-3">
+                  <p className="text-xs text-gray-500">
+                    For networks that only reach OpenRouter and Supabase through a proxy. The system's
+                    HTTP_PROXY/HTTPS_PROXY are used when nothing is saved here. Local backend traffic never uses the proxy.
+                  </p>
+                  {([
+                    ['http_proxy', 'HTTP Proxy', 'http://proxy.example.com:8080'],
+                    ['https_proxy', 'HTTPS Proxy', 'http://proxy.example.com:8080'],
+                    ['no_proxy', 'Bypass Proxy For', 'internal.example.com,.corp'],
+                  ] as const).map(([key, label, placeholder]) => (
+                    <div key={key}>
+                      <label className="block text-sm font-medium text-gray-700 mb-1">{label}</label>
+                      <input
+                        type="text"
+                        value={proxy[key] ?? ''}
+                        placeholder={placeholder}
+                        onChange={(e) => setProxy(prev => ({ ...prev, [key]: e.target.value || null }))}
+                        className="w-full px-3 py-1.5 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
+                      />
+                    </div>
+                  ))}
+                  <button
+                    onClick={saveProxy}
+                    disabled={proxySaving}
+                    className="px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed flex items-center gap-2"
+                  >
+                    <Save className="w-4 h-4" />
+                    {proxySaving ? 'Saving...' : 'Save Proxy Settings'}
+                  </button>
+                </div>
+              </details>
             </div>
 
             <div className="bg-white rounded-lg border border-slate-200 p-6">
@@ -759,6 +1240,55 @@ export const Settings: React.FC = () => {
                   </div>
                 </button>
 
+                <button
+                  onClick={openRawEnvEditor}
+                  className="w-full px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md flex items-center gap-2 text-left"
+                >
+                  <FileText className="w-4 h-4" />
+                  <div>
+                    <div className="font-medium">Edit Environment File In App</div>
+                    <div className="text-sm text-gray-500">Edit the raw .env here; it is validated before saving</div>
+                  </div>
+                </button>
+
+                {rawEnv !== null && (
+                  <div className="space-y-2">
+                    <textarea
+                      value={rawEnv}
+                      onChange={(e) => setRawEnv(e.target.value)}
+                      spellCheck={false}
+                      className="w-full h-64 px-3 py-2 border border-gray-300 rounded-md font-mono text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                    />
+                    <div className="flex gap-2">
+                      <button
+                        onClick={saveRawEnv}
+                        disabled={rawEnvSaving}
+                        className="px-4 py-2 bg-blue-600 text-white rounded-md hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed flex items-center gap-2"
+                      >
+                        <Save className="w-4 h-4" />
+                        {rawEnvSaving ? 'Saving...' : 'Save File'}
+                      </button>
+                      <button
+                        onClick={() => setRawEnv(null)}
+                        className="px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md"
+                      >
+                        Cancel
+                      </button>
+                    </div>
+                  </div>
+                )}
+
+                <button
+                  onClick={checkEnvFile}
+                  className="w-full px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md flex items-center gap-2 text-left"
+                >
+                  <ShieldCheck className="w-4 h-4" />
+                  <div>
+                    <div className="font-medium">Check Configuration File</div>
+                    <div className="text-sm text-gray-500">Look for damage in the .env and restore the last backup if needed</div>
+                  </div>
+                </button>
+
                 <button
                   onClick={openConfigFolder}
                   className="w-full px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md flex items-center gap-2 text-left"
@@ -769,6 +1299,177 @@ export const Settings: React.FC = () => {
                     <div className="text-sm text-gray-500">Browse the application data directory</div>
                   </div>
                 </button>
+
+                <button
+                  onClick={exportSupportBundle}
+                  className="w-full px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md flex items-center gap-2 text-left"
+                >
+                  <Archive className="w-4 h-4" />
+                  <div>
+                    <div className="font-medium">Export Support Bundle</div>
+                    <div className="text-sm text-gray-500">Save logs and redacted diagnostics as a .zip to attach to an issue</div>
+                  </div>
+                </button>
+                <label className="flex items-center gap-2 text-sm text-gray-700 px-1">
+                  <input
+                    type="checkbox"
+                    checked={bundleScreenshots}
+                    onChange={e => setBundleScreenshots(e.target.checked)}
+                  />
+                  Include screenshots (they may show student work)
+                </label>
+
+                <button
+                  onClick={captureScreenshot}
+                  className="w-full px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md flex items-center gap-2 text-left"
+                >
+                  <Camera className="w-4 h-4" />
+                  <div>
+                    <div className="font-medium">Take Screenshot</div>
+                    <div className="text-sm text-gray-500">Save a picture of this window for a bug report</div>
+                  </div>
+                </button>
+
+                <div className="flex gap-2">
+                  <button
+                    onClick={exportPreferences}
+                    className="flex-1 px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md flex items-center gap-2 text-left"
+                  >
+                    <Download className="w-4 h-4" />
+                    <div className="font-medium">Export Preferences</div>
+                  </button>
+                  <button
+                    onClick={importPreferences}
+                    className="flex-1 px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md flex items-center gap-2 text-left"
+                  >
+                    <Upload className="w-4 h-4" />
+                    <div className="font-medium">Import Preferences</div>
+                  </button>
+                </div>
+
+                <button
+                  onClick={runBenchmark}
+                  disabled={benchmarking}
+                  className="w-full px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md flex items-center gap-2 text-left disabled:opacity-50"
+                >
+                  <Gauge className="w-4 h-4" />
+                  <div>
+                    <div className="font-medium">{benchmarking ? 'Measuring...' : 'Measure Backend Latency'}</div>
+                    <div className="text-sm text-gray-500">
+                      {latency
+                        ? `${latency.samples} requests: min ${latency.min_ms.toFixed(1)}ms · median ${latency.median_ms.toFixed(1)}ms · p95 ${latency.p95_ms.toFixed(1)}ms · max ${latency.max_ms.toFixed(1)}ms${latency.failures ? ` · ${latency.failures} failed` : ''}`
+                        : 'Time round trips to the local backend, separate from model grading time'}
+                    </div>
+                  </div>
+                </button>
+
+                {restartStats && (
+                  <div className={`px-4 py-2 rounded-md text-sm ${restartStats.crash_looping ? 'bg-red-50 text-red-800' : 'bg-gray-50 text-gray-700'}`}>
+                    <div className="flex items-center justify-between">
+                      <span className="font-medium">Backend Stability</span>
+                      <div className="flex gap-3">
+                        <button onClick={() => invoke<RestartStats>('get_restart_stats').then(setRestartStats)} className="text-blue-600 hover:underline">
+                          Refresh
+                        </button>
+                        <button onClick={resetRestartStats} className="text-blue-600 hover:underline">
+                          Reset
+                        </button>
+                      </div>
+                    </div>
+                    <div>
+                      Since {new Date(restartStats.since_ms).toLocaleString()}: {restartStats.restarts} restart{restartStats.restarts === 1 ? '' : 's'}, {restartStats.crashes} crash{restartStats.crashes === 1 ? '' : 'es'}
+                      {restartStats.last_crash_ms && ` (last at ${new Date(restartStats.last_crash_ms).toLocaleTimeString()})`}
+                    </div>
+                    {restartStats.crash_looping && (
+                      <div>The backend has crashed {restartStats.recent_crashes} times in the last 10 minutes. Run the self-check below to see why.</div>
+                    )}
+                  </div>
+                )}
+
+                <button
+                  onClick={runSelfCheck}
+                  disabled={selfChecking}
+                  className="w-full px-4 py-2 bg-gray-100 hover:bg-gray-200 rounded-md flex items-center gap-2 text-left disabled:opacity-50"
+                >
+                  <Stethoscope className="w-4 h-4" />
+                  <div>
+                    <div className="font-medium">{selfChecking ? 'Running Self-Check...' : 'Run Backend Self-Check'}</div>
+                    <div className="text-sm text-gray-500">Start a separate copy of the backend once to see why it fails to boot</div>
+                  </div>
+                </button>
+                {diagnostic && (
+                  <div className="text-sm">
+                    <div className={diagnostic.exit_code === 0 ? 'text-green-700' : 'text-red-700'}>
+                      {diagnostic.spawn_error
+                        ? `Couldn't start the backend: ${diagnostic.spawn_error}`
+                        : diagnostic.timed_out
+                          ? `Still running after ${Math.round(diagnostic.elapsed_ms / 1000)}s, so it was stopped`
+                          : `Exited with code ${diagnostic.exit_code ?? 'unknown'} after ${diagnostic.elapsed_ms}ms`}
+                      {diagnostic.truncated && ' (output truncated)'}
+                    </div>
+                    {diagnostic.cli && !diagnostic.cli.compatible && (
+                      <div className="text-red-700">
+                        The bundled backend doesn't accept the arguments this app starts it with
+                        {diagnostic.cli.error
+                          ? ` (${diagnostic.cli.error})`
+                          : ` (missing ${diagnostic.cli.flags.filter(f => f.required && !f.present).map(f => f.flag).join(', ')})`}
+                        ; reinstalling the app should fix this.
+                      </div>
+                    )}
+                    {(diagnostic.stdout.length > 0 || diagnostic.stderr.length > 0) && (
+                      <pre className="mt-1 max-h-64 overflow-auto bg-gray-900 text-gray-100 text-xs p-2 rounded-md whitespace-pre-wrap">
+                        {[...diagnostic.stdout, ...diagnostic.stderr.map(l => `[stderr] ${l}`)].join('\n')}
+                      </pre>
+                    )}
+                  </div>
+                )}
+
+                <label className="w-full px-4 py-2 bg-gray-100 rounded-md flex items-center gap-3 cursor-pointer">
+                  <input
+                    type="checkbox"
+                    checked={backgroundStart}
+                    onChange={(e) => toggleBackgroundStart(e.target.checked)}
+                    className="h-4 w-4"
+                  />
+                  <div>
+                    <div className="font-medium">Start Backend In Background</div>
+                    <div className="text-sm text-gray-500">Open the app immediately and start grading once the backend is ready</div>
+                  </div>
+                </label>
+
+                {hardwareAcceleration?.supported && (
+                  <label className="w-full px-4 py-2 bg-gray-100 rounded-md flex items-center gap-3 cursor-pointer">
+                    <input
+                      type="checkbox"
+                      checked={hardwareAcceleration.enabled}
+                      onChange={(e) => toggleHardwareAcceleration(e.target.checked)}
+                      className="h-4 w-4"
+                    />
+                    <div>
+                      <div className="font-medium">Hardware Acceleration</div>
+                      <div className="text-sm text-gray-500">
+                        Turn off if the window flickers, stays blank or draws garbled; applies after a restart
+                        {hardwareAcceleration.enabled !== hardwareAcceleration.active && ' (restart pending)'}
+                      </div>
+                    </div>
+                  </label>
+                )}
+
+                <label className="w-full px-4 py-2 bg-gray-100 rounded-md flex items-center justify-between gap-3">
+                  <div>
+                    <div className="font-medium">App Log Level</div>
+                    <div className="text-sm text-gray-500">How much the desktop shell writes to its console; backend logs are unaffected</div>
+                  </div>
+                  <select
+                    value={logLevel}
+                    onChange={(e) => changeLogLevel(e.target.value)}
+                    className="px-2 py-1 border border-gray-300 rounded-md bg-white"
+                  >
+                    {['off', 'error', 'warn', 'info', 'debug', 'trace'].map(level => (
+                      <option key={level} value={level}>{level}</option>
+                    ))}
+                  </select>
+                </label>
               </div>
 
               <div className="mt-4 p-3 bg-yellow-50 border border-yellow-200 rounded-md">